@@ -0,0 +1,138 @@
+use crate::{Bowler, Game, GameStats, ScoreProvider, TenPinScoreProvider};
+
+/// A Baker format team [Game], where the [Bowler]s of a team take turns bowling frames.
+///
+/// The first [Bowler] bowls the first frame, the second [Bowler] bowls the second frame, and so on,
+/// starting over with the first [Bowler] once every [Bowler] has had a turn.
+/// With five [Bowler]s, each bowls two frames, and the fifth [Bowler] bowls the final frame.
+#[derive(Clone, Debug, Default, Hash)]
+pub struct BakerGame {
+    /// The [Bowler]s of the team, in the order they bowl.
+    pub bowlers: Vec<Bowler>,
+    /// The team [Game].
+    pub game: Game
+}
+
+impl BakerGame {
+    /// Create a new [BakerGame].
+    pub fn new(bowlers: Vec<Bowler>, game: Game) -> Self {
+        Self {
+            bowlers,
+            game
+        }
+    }
+
+    /// Find the index of the [Bowler] who bowls a frame.
+    ///
+    /// Returns `None` if there are no [Bowler]s or the frame is past the end of the [Game].
+    pub fn bowler_for_frame(&self, frame: usize) -> Option<usize> {
+        (!self.bowlers.is_empty() && frame < self.game.frame_count).then(|| frame % self.bowlers.len())
+    }
+
+    /// Get the indexes of the frames bowled by a [Bowler].
+    pub fn frames_for(&self, bowler: usize) -> Vec<usize> {
+        (0..self.game.frame_count).filter(|frame| self.bowler_for_frame(*frame) == Some(bowler)).collect()
+    }
+
+    /// Calculate the [GameStats] for the frames bowled by a [Bowler].
+    ///
+    /// The frames are treated as a single [Game], so a [Bowler] with a mark in every frame has a clean game,
+    /// as long as the final frame is complete if they bowled it.
+    /// Strike streaks only carry over between frames the [Bowler] bowled back to back.
+    pub fn bowler_stats(&self, bowler: usize) -> GameStats {
+        let owned = self.frames_for(bowler);
+        let game = self.game_of(&owned);
+        let mut stats = GameStats::new(&game);
+
+        // Only the bowler of the final frame needs its bonus ball for a clean game
+        let final_frame = owned.last().is_some_and(|last| last + 1 == self.game.frame_count);
+        stats.clean_games = GameStats::is_clean_game(&game, final_frame) as usize;
+        stats.longest_streak = owned.chunk_by(|a, b| *b == a + 1).map(|run| self.game_of(run).stats().longest_streak).max().unwrap_or(0);
+        stats
+    }
+
+    /// Create a [Game] from some of the frames of the team [Game].
+    fn game_of(&self, frames: &[usize]) -> Game {
+        Game::with_frame_count(frames.len(), frames.iter().filter_map(|frame| self.game.frame(*frame)).copied().collect())
+    }
+
+    /// Calculate the [GameStats] of each [Bowler], in bowling order.
+    pub fn contributions(&self) -> Vec<GameStats> {
+        (0..self.bowlers.len()).map(|bowler| self.bowler_stats(bowler)).collect()
+    }
+
+    /// Calculate the score for each frame of the team [Game] using a [ScoreProvider].
+    pub fn computed_with_provider(&self, provider: impl ScoreProvider) -> Vec<usize> {
+        self.game.computed_with_provider(provider)
+    }
+
+    /// Calculate the score for each frame of the team [Game] using the [TenPinScoreProvider].
+    pub fn computed(&self) -> Vec<usize> {
+        self.computed_with_provider(TenPinScoreProvider)
+    }
+
+    /// Calculate the score of the team [Game] using a [ScoreProvider].
+    pub fn score_with_provider(&self, provider: impl ScoreProvider) -> usize {
+        self.game.score_with_provider(provider)
+    }
+
+    /// Calculate the score of the team [Game] using the [TenPinScoreProvider].
+    pub fn score(&self) -> usize {
+        self.score_with_provider(TenPinScoreProvider)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BakerGame, Bowler, CurrentFrameScoreProvider};
+    use crate::fixtures::{game, perfect_game};
+
+    fn baker() -> BakerGame {
+        let bowlers = ["Homer", "Lenny", "Carl", "Moe", "Barney"].map(|name| Bowler::new(name, 150)).to_vec();
+        BakerGame::new(bowlers, game())
+    }
+
+    #[test]
+    fn frame_ownership() {
+        let baker = baker();
+
+        assert_eq!(baker.bowler_for_frame(0), Some(0));
+        assert_eq!(baker.bowler_for_frame(9), Some(4));
+        assert_eq!(baker.bowler_for_frame(10), None);
+        assert_eq!(baker.frames_for(2), vec![2, 7]);
+        assert_eq!(BakerGame::default().bowler_for_frame(0), None);
+    }
+
+    #[test]
+    fn contributions() {
+        let contributions = baker().contributions();
+
+        // Homer bowled X and 7/, Barney bowled X and X X 8
+        assert_eq!(contributions[0].strikes, 1);
+        assert_eq!(contributions[0].spares, 1);
+        assert_eq!(contributions[0].pins, 20);
+        assert_eq!(contributions[4].strikes, 3);
+        assert_eq!(contributions[4].pins, 38);
+        assert!(contributions[0].is_clean());
+        assert!(!contributions[1].is_clean());
+        assert_eq!(contributions.iter().map(|stats| stats.pins).sum::<usize>(), 116);
+    }
+
+    #[test]
+    fn streaks() {
+        let pair = BakerGame::new(vec![Bowler::new("Homer", 150), Bowler::new("Marge", 150)], perfect_game());
+        let solo = BakerGame::new(vec![Bowler::new("Homer", 150)], perfect_game());
+
+        assert_eq!(pair.bowler_stats(0).longest_streak, 1);
+        assert_eq!(pair.bowler_stats(1).longest_streak, 3);
+        assert_eq!(solo.bowler_stats(0).longest_streak, 12);
+    }
+
+    #[test]
+    fn team_score() {
+        let baker = baker();
+
+        assert_eq!(baker.score(), 190);
+        assert_eq!(baker.score_with_provider(CurrentFrameScoreProvider), baker.game.score_with_provider(CurrentFrameScoreProvider));
+    }
+}
\ No newline at end of file
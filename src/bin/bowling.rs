@@ -0,0 +1,312 @@
+//! Command-line scorer for bowling games written in scoresheet notation.
+//!
+//! ```text
+//! bowling <score|max|stats> [--format text|json|csv] [-f PATH | NOTATION...]
+//! ```
+//!
+//! The game is read from the arguments, or from a file or stdin with one game per line.
+
+use std::{env, fmt::Write, fs, io::{self, Read}, process::ExitCode};
+
+use bowling_rs::{Game, GameStats};
+use serde_json::json;
+
+const USAGE: &str = "usage: bowling <score|max|stats> [--format text|json|csv] [-f PATH | NOTATION...]
+
+Commands:
+  score    Print the running total of each frame
+  max      Print the current and maximum possible score
+  stats    Print the statistics of every game combined
+
+Options:
+  --format FORMAT    Print the results as text, json or csv
+  -f, --file PATH    Read the games from a file
+
+The game is read from NOTATION, or from --file or stdin with one game per line, like:
+  bowling score \"X 7/ 9- X X 81 -/ X X XX7\"";
+
+/// What to calculate for each game.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Command {
+    Score,
+    Max,
+    Stats
+}
+
+/// How to print the results.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Format {
+    Text,
+    /// One JSON object per game, or a single object for stats.
+    Json,
+    Csv
+}
+
+/// The parsed command-line arguments.
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct Options {
+    command: Command,
+    format: Format,
+    file: Option<String>,
+    notation: Vec<String>
+}
+
+/// Parse the command-line arguments, without the program name.
+fn parse_args(args: impl IntoIterator<Item = String>) -> Result<Options, String> {
+    let mut args = args.into_iter();
+
+    let command = match args.next().as_deref() {
+        Some("score") => Command::Score,
+        Some("max") => Command::Max,
+        Some("stats") => Command::Stats,
+        Some(command) => return Err(format!("unknown command `{}`", command)),
+        None => return Err(String::from("missing command"))
+    };
+
+    let mut options = Options {
+        command,
+        format: Format::Text,
+        file: None,
+        notation: vec![]
+    };
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--format" => {
+                options.format = match args.next().as_deref() {
+                    Some("text") => Format::Text,
+                    Some("json") => Format::Json,
+                    Some("csv") => Format::Csv,
+                    Some(format) => return Err(format!("unknown format `{}`", format)),
+                    None => return Err(String::from("missing format"))
+                };
+            },
+            "-f" | "--file" => options.file = Some(args.next().ok_or("missing file")?),
+            // Notation can start with `-` for a gutter ball, so only other arguments are options
+            flag if flag.starts_with('-') && !is_notation(flag) => return Err(format!("unknown option `{}`", flag)),
+            _ => options.notation.push(arg)
+        }
+    }
+
+    if options.file.is_some() && !options.notation.is_empty() {
+        return Err(String::from("--file can't be used with NOTATION"));
+    }
+
+    Ok(options)
+}
+
+/// Check if an argument only has scoresheet notation symbols, such as `-/` for a gutter ball then a spare.
+fn is_notation(arg: &str) -> bool {
+    arg.chars().all(|c| c.is_ascii_digit() || c.is_whitespace() || "XxFfSs/-|".contains(c) || ('①'..='⑨').contains(&c))
+}
+
+/// Parse every game, with one game per non-empty line.
+fn parse_games(input: &str) -> Result<Vec<Game>, String> {
+    let lines: Vec<&str> = input.lines().filter(|line| !line.trim().is_empty()).collect();
+
+    if lines.is_empty() {
+        return Err(String::from("no games to score"));
+    }
+
+    lines.iter().enumerate().map(|(i, line)| Game::from_notation(line.trim()).map_err(|error| {
+        if lines.len() == 1 { error.to_string() } else { format!("game {}: {}", i + 1, error) }
+    })).collect()
+}
+
+/// Format an optional number as text, with `None` left blank.
+fn optional(value: Option<impl ToString>) -> String {
+    value.map(|value| value.to_string()).unwrap_or_default()
+}
+
+/// Print the results of a [Command] for every game.
+fn render(command: Command, format: Format, games: &[Game]) -> String {
+    let mut output = String::new();
+
+    match (command, format) {
+        (Command::Score, Format::Text) => {
+            let sheets: Vec<String> = games.iter().map(|game| format!("{}\nScore: {}", game, game.score())).collect();
+            output = sheets.join("\n\n");
+        },
+        (Command::Score, Format::Json) => {
+            for game in games {
+                let running: Vec<Option<usize>> = game.frame_scores().iter().map(|score| score.running).collect();
+                let _ = writeln!(output, "{}", json!({ "notation": game.to_notation(), "frames": running, "score": game.score() }));
+            }
+        },
+        (Command::Score, Format::Csv) => {
+            output += "game,frame,total\n";
+
+            for (i, game) in games.iter().enumerate() {
+                for (frame, score) in game.frame_scores().iter().enumerate() {
+                    let _ = writeln!(output, "{},{},{}", i + 1, frame + 1, optional(score.running));
+                }
+            }
+        },
+        (Command::Max, Format::Text) => {
+            let lines: Vec<String> = games.iter().map(|game| format!("Score: {}\nMax: {}", game.score(), game.max())).collect();
+            output = lines.join("\n\n");
+        },
+        (Command::Max, Format::Json) => {
+            for game in games {
+                let _ = writeln!(output, "{}", json!({ "notation": game.to_notation(), "score": game.score(), "max": game.max() }));
+            }
+        },
+        (Command::Max, Format::Csv) => {
+            output += "game,score,max\n";
+
+            for (i, game) in games.iter().enumerate() {
+                let _ = writeln!(output, "{},{},{}", i + 1, game.score(), game.max());
+            }
+        },
+        (Command::Stats, format) => {
+            let stats: GameStats = games.iter().collect();
+            output = render_stats(&stats, format);
+        }
+    }
+
+    output.trim_end().to_string()
+}
+
+/// Print [GameStats].
+fn render_stats(stats: &GameStats, format: Format) -> String {
+    let percent = |rate: Option<f64>| optional(rate.map(|rate| format!("{:.1}%", rate * 100.0)));
+    let average = stats.first_ball_average().map(|average| format!("{:.2}", average));
+
+    match format {
+        Format::Text => [
+            format!("Games: {}", stats.games),
+            format!("Strikes: {}", stats.strikes),
+            format!("Spares: {}", stats.spares),
+            format!("Open frames: {}", stats.opens),
+            format!("Pins: {}", stats.pins),
+            format!("First ball average: {}", optional(average)),
+            format!("Strike rate: {}", percent(stats.strike_rate())),
+            format!("Spare conversion: {}", percent(stats.spare_conversion())),
+            format!("Single-pin conversion: {}", percent(stats.single_pin_conversion())),
+            format!("Clean games: {}", stats.clean_games),
+            format!("Longest streak: {}", stats.longest_streak)
+        ].join("\n"),
+        Format::Json => json!({
+            "games": stats.games,
+            "strikes": stats.strikes,
+            "spares": stats.spares,
+            "opens": stats.opens,
+            "pins": stats.pins,
+            "first_ball_average": stats.first_ball_average(),
+            "strike_rate": stats.strike_rate(),
+            "spare_conversion": stats.spare_conversion(),
+            "single_pin_conversion": stats.single_pin_conversion(),
+            "clean_games": stats.clean_games,
+            "longest_streak": stats.longest_streak
+        }).to_string(),
+        Format::Csv => format!(
+            "games,strikes,spares,opens,pins,first_ball_average,strike_rate,spare_conversion,single_pin_conversion,clean_games,longest_streak\n{},{},{},{},{},{},{},{},{},{},{}",
+            stats.games,
+            stats.strikes,
+            stats.spares,
+            stats.opens,
+            stats.pins,
+            optional(average),
+            optional(stats.strike_rate()),
+            optional(stats.spare_conversion()),
+            optional(stats.single_pin_conversion()),
+            stats.clean_games,
+            stats.longest_streak
+        )
+    }
+}
+
+fn main() -> ExitCode {
+    let options = match parse_args(env::args().skip(1)) {
+        Ok(options) => options,
+        Err(error) => {
+            eprintln!("error: {}\n\n{}", error, USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    let input = if !options.notation.is_empty() {
+        Ok(options.notation.join(" "))
+    } else if let Some(file) = &options.file {
+        fs::read_to_string(file).map_err(|error| format!("can't read {}: {}", file, error))
+    } else {
+        let mut input = String::new();
+        io::stdin().read_to_string(&mut input).map(|_| input).map_err(|error| format!("can't read stdin: {}", error))
+    };
+
+    match input.and_then(|input| parse_games(&input)) {
+        Ok(games) => {
+            println!("{}", render(options.command, options.format, &games));
+            ExitCode::SUCCESS
+        },
+        Err(error) => {
+            eprintln!("error: {}", error);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{parse_args, parse_games, render, Command, Format, Options};
+
+    const GAME: &str = "X 7/ 9- X X 81 -/ X X XX7";
+
+    fn args(args: &[&str]) -> Result<Options, String> {
+        parse_args(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn arguments() {
+        assert_eq!(args(&["score", GAME]), Ok(Options { command: Command::Score, format: Format::Text, file: None, notation: vec![GAME.to_string()] }));
+        assert_eq!(args(&["max", "--format", "csv", "--file", "games.txt"]).map(|options| (options.format, options.file)), Ok((Format::Csv, Some(String::from("games.txt")))));
+        assert_eq!(args(&["max", "-f", "games.txt"]).map(|options| options.file), Ok(Some(String::from("games.txt"))));
+        assert!(args(&["bowl"]).is_err());
+        assert!(args(&["stats", "--format", "xml"]).is_err());
+        assert_eq!(args(&["score", "--", "X"]).map(|options| options.notation), Ok(vec![String::from("--"), String::from("X")]));
+        assert_eq!(args(&["score", "-/", "X"]).map(|options| options.notation), Ok(vec![String::from("-/"), String::from("X")]));
+    }
+
+    #[test]
+    fn invalid_arguments() {
+        assert_eq!(args(&["stats", "--verbose", "X"]), Err(String::from("unknown option `--verbose`")));
+        assert_eq!(args(&["stats", "-i", "games.txt"]), Err(String::from("unknown option `-i`")));
+        assert_eq!(args(&["stats", "--Verbose"]), Err(String::from("unknown option `--Verbose`")));
+        assert_eq!(args(&["stats", "-h"]), Err(String::from("unknown option `-h`")));
+        assert_eq!(args(&["score", "--file", "games.txt", GAME]), Err(String::from("--file can't be used with NOTATION")));
+    }
+
+    #[test]
+    fn games() {
+        assert_eq!(parse_games(&format!("{}\n\nX X X\n", GAME)).unwrap().len(), 2);
+        assert_eq!(parse_games("X 78").err(), Some(String::from("frame 2, shot 2: 8 pins is more than were standing")));
+        assert_eq!(parse_games("X\nX 78").err(), Some(String::from("game 2: frame 2, shot 2: 8 pins is more than were standing")));
+        assert!(parse_games("\n").is_err());
+    }
+
+    #[test]
+    fn score() {
+        let games = parse_games(GAME).unwrap();
+
+        assert!(render(Command::Score, Format::Text, &games).ends_with("Score: 211"));
+        assert_eq!(render(Command::Score, Format::Json, &games), r#"{"frames":[20,39,48,76,95,104,124,154,184,211],"notation":"X 7/ 9- X X 81 -/ X X XX7","score":211}"#);
+        assert!(render(Command::Score, Format::Csv, &games).starts_with("game,frame,total\n1,1,20\n1,2,39\n"));
+    }
+
+    #[test]
+    fn max() {
+        let games = parse_games("X X X").unwrap();
+
+        assert_eq!(render(Command::Max, Format::Text, &games), "Score: 60\nMax: 300");
+        assert_eq!(render(Command::Max, Format::Csv, &games), "game,score,max\n1,60,300");
+    }
+
+    #[test]
+    fn stats() {
+        let games = parse_games(&format!("{}\nX X X", GAME)).unwrap();
+        let text = render(Command::Stats, Format::Text, &games);
+
+        assert!(text.starts_with("Games: 2\nStrikes: 10\n"));
+        assert!(render(Command::Stats, Format::Json, &games).contains(r#""strikes":10"#));
+    }
+}
\ No newline at end of file
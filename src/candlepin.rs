@@ -0,0 +1,87 @@
+/// [ScoreProvider](crate::ScoreProvider) for candlepin bowling.
+///
+/// Each [Frame](crate::Frame) has up to three balls, with the third stored in [Frame::bonus](crate::Frame::bonus).
+/// - A strike is worth 10, plus the next two balls.
+/// - A spare on the second ball is worth 10, plus the next ball.
+/// - A "ten box" on the third ball is worth 10, with no bonus.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct CandlepinScoreProvider;
+
+three_ball_provider!(CandlepinScoreProvider);
+
+#[cfg(test)]
+mod tests {
+    use crate::{CandlepinScoreProvider, Frame, FrameScore, Game, Score, fixtures};
+
+    #[test]
+    fn perfect_game() {
+        let game = fixtures::perfect_game();
+
+        assert_eq!(game.score_with_provider(CandlepinScoreProvider), 300);
+    }
+
+    #[test]
+    fn ten_boxes() {
+        let game = Game::new(vec![Frame::with_third(Score::PINS(3), Score::PINS(4), Score::SPARE); 10]);
+
+        assert!(game.frame(0).unwrap().is_ten_box());
+        assert_eq!(game.score_with_provider(CandlepinScoreProvider), 100);
+    }
+
+    #[test]
+    fn typical_game() {
+        let game = Game::new(vec![
+            Frame::strike(),
+            Frame::spare(7),
+            Frame::with_third(Score::PINS(3), Score::PINS(4), Score::PINS(2)),
+            Frame::with_third(Score::PINS(5), Score::PINS(1), Score::SPARE),
+            Frame::new(Score::PINS(6), Score::SPARE),
+            Frame::with_third(Score::PINS(2), Score::PINS(0), Score::PINS(3)),
+            Frame::strike(),
+            Frame::strike(),
+            Frame::with_third(Score::PINS(8), Score::PINS(1), Score::PINS(0)),
+            Frame::with_third(Score::STRIKE, Score::PINS(4), Score::PINS(3))
+        ]);
+
+        assert_eq!(game.computed_with_provider(CandlepinScoreProvider), vec![20, 33, 42, 52, 64, 69, 97, 116, 125, 142]);
+    }
+
+    #[test]
+    fn frame_scores_pending_strike() {
+        let game = Game::new(vec![Frame::with_third(Score::PINS(3), Score::PINS(4), Score::PINS(2)), Frame::strike(), Frame::new(Score::PINS(6), Score::EMPTY)]);
+        let scores = game.frame_scores_with_provider(CandlepinScoreProvider);
+
+        assert_eq!(scores[0], FrameScore { running: Some(9), base: 9, bonus: 0, resolved: true });
+        assert_eq!(scores[1], FrameScore { running: None, base: 10, bonus: 6, resolved: false });
+        assert_eq!(scores[2], FrameScore { running: None, base: 6, bonus: 0, resolved: false });
+    }
+
+    #[test]
+    fn frame_scores_final_frame() {
+        let mut game = Game::new(vec![Frame::strike(); 9]);
+        game.add_frame(Frame::new(Score::PINS(3), Score::PINS(4)));
+        let scores = game.frame_scores_with_provider(CandlepinScoreProvider);
+
+        assert_eq!(scores[8].running, Some(250));
+        assert_eq!(scores[9].running, None);
+        assert_eq!(game.computed_with_provider(CandlepinScoreProvider)[9], 257);
+    }
+
+    #[test]
+    fn max_empty() {
+        let game = Game::new(vec![]);
+        assert_eq!(game.max_with_provider(CandlepinScoreProvider), 300);
+    }
+
+    #[test]
+    fn max_after_open_frame() {
+        let game = Game::new(vec![Frame::with_third(Score::PINS(3), Score::PINS(4), Score::PINS(2))]);
+        assert_eq!(game.max_with_provider(CandlepinScoreProvider), 279);
+    }
+
+    #[test]
+    fn max_mid_frame() {
+        let game = Game::new(vec![Frame::new(Score::PINS(3), Score::PINS(4))]);
+        assert_eq!(game.max_with_provider(CandlepinScoreProvider), 280);
+    }
+}
\ No newline at end of file
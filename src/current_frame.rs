@@ -0,0 +1,135 @@
+use crate::{Frame, FrameScore, Game, ScoreProvider};
+
+/// [ScoreProvider] for World Bowling "current frame" scoring.
+///
+/// Every [Frame] is scored as soon as it is finished, without looking ahead.
+/// - A strike is worth 30.
+/// - A spare is worth 10, plus the pins from the first ball of the [Frame].
+/// - An open [Frame] is worth its pins.
+///
+/// The final [Frame] has no bonus shots, so any that were bowled are ignored.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct CurrentFrameScoreProvider;
+
+impl CurrentFrameScoreProvider {
+    /// Calculate the value of a [Frame].
+    pub fn value(frame: &Frame) -> usize {
+        let shots = frame.shots();
+
+        if shots.first() == Some(&10) {
+            30
+        } else if shots.len() >= 2 && shots[0] + shots[1] >= 10 {
+            10 + shots[0]
+        } else {
+            shots.iter().take(2).sum()
+        }
+    }
+
+    /// Check if every shot of a [Frame] has been bowled.
+    fn is_complete(frame: &Frame) -> bool {
+        let shots = frame.shots();
+        shots.first() == Some(&10) || shots.len() >= 2
+    }
+}
+
+impl ScoreProvider for CurrentFrameScoreProvider {
+    fn computed(&self, game: &Game) -> Vec<usize> {
+        let mut frames = vec![];
+        let mut score = 0;
+
+        for frame in game.iter().take(game.frame_count) {
+            score += Self::value(frame);
+            frames.push(score);
+        }
+
+        frames
+    }
+
+    fn frame_scores(&self, game: &Game) -> Vec<FrameScore> {
+        let mut scores = vec![];
+        let mut running = Some(0);
+
+        for frame in game.iter().take(game.frame_count) {
+            let base = Self::value(frame);
+            let resolved = Self::is_complete(frame);
+
+            running = running.filter(|_| resolved).map(|score| score + base);
+
+            scores.push(FrameScore {
+                running,
+                base,
+                bonus: 0,
+                resolved
+            });
+        }
+
+        scores
+    }
+
+    fn score(&self, game: &Game) -> usize {
+        game.iter().take(game.frame_count).map(Self::value).sum()
+    }
+
+    fn max(&self, game: &Game, provider: &impl ScoreProvider) -> usize {
+        let mut frames = vec![];
+
+        for i in 0..game.frame_count {
+            let frame = match game.frame(i) {
+                Some(frame) if frame.first.is_empty() => Frame::strike(),
+                Some(frame) if !frame.is_strike() && frame.second.is_empty() => Frame::spare(frame.first.value()),
+                Some(frame) => *frame,
+                None => Frame::strike()
+            };
+
+            // Bonus shots don't count, so they are left off
+            frames.push(Frame { bonus: None, ..frame });
+        }
+
+        Game::with_frame_count(game.frame_count, frames).score_with_provider(*provider)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CurrentFrameScoreProvider, Frame, Game, Score};
+
+    #[test]
+    fn frame_values() {
+        assert_eq!(CurrentFrameScoreProvider::value(&Frame::strike()), 30);
+        assert_eq!(CurrentFrameScoreProvider::value(&Frame::spare(9)), 19);
+        assert_eq!(CurrentFrameScoreProvider::value(&Frame::new(Score::PINS(7), Score::PINS(2))), 9);
+    }
+
+    #[test]
+    fn perfect_game() {
+        let game = Game::new(vec![Frame::strike(); 10]);
+
+        assert_eq!(game.score_with_provider(CurrentFrameScoreProvider), 300);
+        assert!(game.is_perfect_with_provider(CurrentFrameScoreProvider));
+    }
+
+    #[test]
+    fn compare_with_ten_pin() {
+        let game = Game::new(vec![Frame::new(Score::PINS(7), Score::PINS(1)), Frame::spare(9), Frame::strike(), Frame::spare(8), Frame::strike(), Frame::strike(), Frame::strike(), Frame::strike(), Frame::strike(), Frame::with_bonus(Score::STRIKE, Score::STRIKE, Score::PINS(7))]);
+
+        assert_eq!(game.score(), 245);
+        assert_eq!(game.computed_with_provider(CurrentFrameScoreProvider), vec![8, 27, 57, 75, 105, 135, 165, 195, 225, 255]);
+        assert_eq!(game.score_with_provider(CurrentFrameScoreProvider), 255);
+    }
+
+    #[test]
+    fn partial_frame() {
+        let game = Game::new(vec![Frame::strike(), Frame::new(Score::PINS(7), Score::EMPTY)]);
+        let scores = game.frame_scores_with_provider(CurrentFrameScoreProvider);
+
+        assert_eq!(scores[0].running, Some(30));
+        assert_eq!(scores[1].running, None);
+    }
+
+    #[test]
+    fn max() {
+        assert_eq!(Game::new(vec![]).max_with_provider(CurrentFrameScoreProvider), 300);
+        assert_eq!(Game::new(vec![Frame::new(Score::PINS(7), Score::EMPTY)]).max_with_provider(CurrentFrameScoreProvider), 287);
+        assert_eq!(Game::new(vec![Frame::new(Score::PINS(7), Score::PINS(1))]).max_with_provider(CurrentFrameScoreProvider), 278);
+    }
+}
\ No newline at end of file
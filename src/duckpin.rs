@@ -0,0 +1,50 @@
+/// [ScoreProvider](crate::ScoreProvider) for duckpin bowling.
+///
+/// Duckpin uses the same three-ball rules as [candlepin](crate::CandlepinScoreProvider), so both score a [Game](crate::Game) the same way.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct DuckpinScoreProvider;
+
+three_ball_provider!(DuckpinScoreProvider);
+
+#[cfg(test)]
+mod tests {
+    use crate::{DuckpinScoreProvider, Frame, Game, Score, fixtures};
+
+    #[test]
+    fn perfect_game() {
+        let game = fixtures::perfect_game();
+
+        assert_eq!(game.score_with_provider(DuckpinScoreProvider), 300);
+    }
+
+    #[test]
+    fn typical_game() {
+        let game = Game::new(vec![
+            Frame::with_third(Score::PINS(6), Score::PINS(2), Score::PINS(1)),
+            Frame::spare(8),
+            Frame::with_third(Score::PINS(4), Score::PINS(3), Score::SPARE),
+            Frame::strike(),
+            Frame::with_third(Score::PINS(7), Score::PINS(0), Score::PINS(2)),
+            Frame::with_third(Score::PINS(5), Score::PINS(3), Score::PINS(0)),
+            Frame::spare(9),
+            Frame::with_third(Score::PINS(3), Score::PINS(3), Score::PINS(3)),
+            Frame::with_third(Score::PINS(6), Score::PINS(1), Score::SPARE),
+            Frame::with_third(Score::PINS(9), Score::SPARE, Score::PINS(7))
+        ]);
+
+        assert_eq!(game.computed_with_provider(DuckpinScoreProvider), vec![9, 23, 33, 50, 59, 67, 80, 89, 99, 116]);
+        assert_eq!(game.max_with_provider(DuckpinScoreProvider), 116);
+    }
+
+    #[test]
+    fn max_300() {
+        let game = Game::new(vec![]);
+        assert_eq!(game.max_with_provider(DuckpinScoreProvider), 300);
+    }
+
+    #[test]
+    fn max_partial_game() {
+        let game = Game::new(vec![Frame::strike(), Frame::with_third(Score::PINS(7), Score::PINS(0), Score::PINS(2))]);
+        assert_eq!(game.max_with_provider(DuckpinScoreProvider), 266);
+    }
+}
\ No newline at end of file
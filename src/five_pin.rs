@@ -0,0 +1,127 @@
+use crate::{three_ball, Frame, FrameScore, Game, PinSet, Score, ScoreProvider};
+
+/// [ScoreProvider] for Canadian five-pin bowling.
+///
+/// Pins are numbered 1 to 5 from left to right, and are worth 2, 3, 5, 3 and 2 points, for 15 points per rack.
+/// Each [Frame] has up to three balls, with the third stored in [Frame::bonus].
+/// - A strike is worth 15, plus the next two balls.
+/// - A spare on the second ball is worth 15, plus the next ball.
+/// - Clearing the pins on the third ball is worth 15, with no bonus.
+///
+/// Shots should be recorded with [Score]::PINSET, so their value comes from the pins that fell.
+/// A [Score]::PINS shot is counted as that many points.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct FivePinScoreProvider;
+
+impl FivePinScoreProvider {
+    /// The point value of each pin, from pin 1 to pin 5.
+    pub const PIN_VALUES: [usize; 5] = [2, 3, 5, 3, 2];
+
+    /// The point value of knocking down every pin.
+    pub const RACK_VALUE: usize = 15;
+
+    /// Calculate the point value of a [PinSet].
+    pub fn value(pins: PinSet) -> usize {
+        pins.iter().filter_map(|pin| Self::PIN_VALUES.get(pin - 1)).sum()
+    }
+
+    /// Get the point value of each shot of a [Frame].
+    ///
+    /// Shots after the first [Score]::EMPTY are ignored.
+    pub fn shots(frame: &Frame) -> Vec<usize> {
+        let mut shots = vec![];
+        let mut standing = Self::RACK_VALUE;
+
+        for score in [Some(frame.first), Some(frame.second), frame.bonus].into_iter().flatten() {
+            let points = match score {
+                Score::EMPTY => break,
+                Score::FOUL => 0,
+                Score::PINS(points) | Score::SPLIT(points) => points,
+                Score::PINSET(pins) => Self::value(pins),
+                Score::SPARE => standing,
+                Score::STRIKE => Self::RACK_VALUE
+            };
+
+            shots.push(points);
+            standing -= points.min(standing);
+
+            // The pins are reset once they've all been knocked down
+            if standing == 0 {
+                standing = Self::RACK_VALUE;
+            }
+        }
+
+        shots
+    }
+}
+
+impl ScoreProvider for FivePinScoreProvider {
+    fn computed(&self, game: &Game) -> Vec<usize> {
+        three_ball::computed(game, Self::RACK_VALUE, Self::shots)
+    }
+
+    fn frame_scores(&self, game: &Game) -> Vec<FrameScore> {
+        three_ball::frame_scores(game, Self::RACK_VALUE, Self::shots)
+    }
+
+    fn max(&self, game: &Game, provider: &impl ScoreProvider) -> usize {
+        three_ball::max(game, provider, Self::RACK_VALUE, Self::shots)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{FivePinScoreProvider, Frame, Game, PinSet, Score, ScoreProvider, TenPinScoreProvider, fixtures};
+
+    fn pins(pins: &[usize]) -> Score {
+        Score::PINSET(PinSet::from_pins(pins))
+    }
+
+    #[test]
+    fn pin_values() {
+        assert_eq!(FivePinScoreProvider::value(PinSet::from_pins(&[1, 2, 3, 4, 5])), 15);
+        assert_eq!(FivePinScoreProvider::value(PinSet::from_pins(&[3])), 5);
+        assert_eq!(FivePinScoreProvider::value(PinSet::from_pins(&[1, 5])), 4);
+    }
+
+    #[test]
+    fn perfect_game() {
+        let game = fixtures::perfect_game();
+
+        assert_eq!(game.score_with_provider(FivePinScoreProvider), 450);
+    }
+
+    #[test]
+    fn typical_game() {
+        let game = Game::new(vec![
+            // Headpin and both threes, then the corners
+            Frame::new(pins(&[2, 3, 4]), pins(&[1, 5])),
+            Frame::with_third(pins(&[1, 2, 3]), pins(&[4]), pins(&[])),
+            Frame::new(pins(&[1, 2, 3, 4, 5]), Score::EMPTY),
+            Frame::with_third(pins(&[3, 4, 5]), pins(&[2]), Score::SPARE)
+        ]);
+
+        assert_eq!(game.computed_with_provider(FivePinScoreProvider), vec![25, 38, 66, 81]);
+    }
+
+    #[test]
+    fn max_450() {
+        let game = Game::new(vec![]);
+        assert_eq!(game.max_with_provider(FivePinScoreProvider), 450);
+    }
+
+    #[test]
+    fn max_partial_game() {
+        let game = Game::new(vec![Frame::new(pins(&[2, 3, 4]), Score::EMPTY)]);
+        assert_eq!(game.max_with_provider(FivePinScoreProvider), 435);
+    }
+
+    #[test]
+    fn perfect_depends_on_provider() {
+        let game = fixtures::perfect_game();
+
+        assert!(game.is_perfect_with_provider(FivePinScoreProvider));
+        assert_eq!(FivePinScoreProvider.perfect(&game), 450);
+        assert_eq!(TenPinScoreProvider.perfect(&game), 300);
+    }
+}
\ No newline at end of file
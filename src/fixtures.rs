@@ -0,0 +1,27 @@
+//! [Game]s shared by the tests of several modules.
+
+use crate::{Frame, Game, Score};
+
+/// A 190 game with six strikes, three spares and two open frames, including a single-pin miss.
+pub(crate) fn game() -> Game {
+    Game::from_notation("X | 9 / | 8 1 | X | X | 7 / | 9 - | X | 9 / | X X 8").unwrap()
+}
+
+/// A perfect 300 game.
+pub(crate) fn perfect_game() -> Game {
+    let mut game = Game::new(vec![Frame::strike(); 9]);
+    game.add_frame(Frame::triple_strike());
+    game
+}
+
+/// A clean 190 game, with a 9 spare in every frame.
+pub(crate) fn clean_game() -> Game {
+    let mut game = Game::new(vec![Frame::spare(9); 9]);
+    game.add_frame(Frame::with_bonus(Score::PINS(9), Score::SPARE, Score::PINS(9)));
+    game
+}
+
+/// A 90 game, with 7 and 2 in every frame.
+pub(crate) fn open_game() -> Game {
+    Game::new(vec![Frame::new(Score::PINS(7), Score::PINS(2)); 10])
+}
@@ -1,15 +1,19 @@
 use std::fmt;
 
-use crate::Score;
+use crate::{PinSet, Score};
 
 /// A bowling [Frame].
 #[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Frame {
     /// The first shot of the [Frame].
     pub first: Score,
     /// The second shot of the [Frame].
     pub second: Score,
     /// The optional bonus shot of the [Frame].
+    ///
+    /// In games with three balls per frame, such as candlepin and duckpin, this holds the third ball.
+    #[cfg_attr(feature = "serde", serde(default))]
     pub bonus: Option<Score>
 }
 
@@ -32,6 +36,11 @@ impl Frame {
         }
     }
 
+    /// Create a new [Frame] with a third ball, as bowled in candlepin and duckpin.
+    pub fn with_third(first: Score, second: Score, third: Score) -> Self {
+        Self::with_bonus(first, second, third)
+    }
+
     /// Create an empty [Frame].
     pub fn empty() -> Self {
         Self::default()
@@ -62,6 +71,49 @@ impl Frame {
         self.first.is_strike()
     }
 
+    /// Get the pins left standing after the first shot of the [Frame].
+    ///
+    /// Returns `None` if the first shot didn't record which pins were knocked down.
+    pub fn leave(&self) -> Option<PinSet> {
+        match self.first {
+            Score::PINSET(pins) => Some(PinSet::all().difference(pins)),
+            score if score.is_strike() => Some(PinSet::empty()),
+            _ => None
+        }
+    }
+
+    /// Check if the [Frame] is a "ten box," where the pins are cleared by the third ball.
+    pub fn is_ten_box(&self) -> bool {
+        let shots = self.shots();
+        shots.len() == 3 && shots[0] + shots[1] < 10 && shots.iter().sum::<usize>() == 10
+    }
+
+    /// Get the number of pins knocked down by each shot of the [Frame].
+    ///
+    /// Shots after the first [Score]::EMPTY are ignored.
+    pub fn shots(&self) -> Vec<usize> {
+        let mut shots = vec![];
+        let mut standing = 10;
+
+        for score in [Some(self.first), Some(self.second), self.bonus].into_iter().flatten() {
+            let pins = match score {
+                Score::EMPTY => break,
+                Score::SPARE => standing,
+                score => score.value()
+            };
+
+            shots.push(pins);
+            standing -= pins.min(standing);
+
+            // The pins are reset once they've all been knocked down
+            if standing == 0 {
+                standing = 10;
+            }
+        }
+
+        shots
+    }
+
     /// Calculate the value of the [Frame].
     pub fn value(&self) -> usize {
         let mut value = self.first.value();
@@ -107,9 +159,9 @@ impl<V> From<Vec<V>> for Frame where V: Into<usize> + Copy {
         let bonus = value.get(2);
 
         Self {
-            first: first.and_then(|f| Some(Score::PINS((*f).into()))).unwrap_or(Score::EMPTY),
-            second: second.and_then(|s| Some(Score::PINS((*s).into()))).unwrap_or(Score::EMPTY),
-            bonus: bonus.and_then(|b| Some(Score::PINS((*b).into()))),
+            first: first.map(|f| Score::PINS((*f).into())).unwrap_or(Score::EMPTY),
+            second: second.map(|s| Score::PINS((*s).into())).unwrap_or(Score::EMPTY),
+            bonus: bonus.map(|b| Score::PINS((*b).into())),
         }
     }
 }
@@ -130,18 +182,29 @@ impl fmt::Display for Frame {
 
 #[cfg(test)]
 mod tests {
-    use crate::{Frame, Score};
+    use crate::{Frame, PinSet, Score};
 
     #[test]
     fn is_spare() {
-        let frame = Frame::new(Score::PINS(5), Score::PINS(7));
+        let frame = Frame::new(Score::PINS(3), Score::PINS(7));
         assert!(frame.is_spare());
+        assert!(!Frame::new(Score::PINS(7), Score::PINS(8)).is_spare());
+    }
+
+    #[test]
+    fn foul_spare() {
+        let frame = Frame::new(Score::FOUL, Score::PINS(10));
+
+        assert!(frame.is_spare());
+        assert_eq!(frame.value(), 10);
+        assert_eq!(frame.shots(), vec![0, 10]);
     }
 
     #[test]
     fn is_strike() {
-        let frame = Frame::new(Score::PINS(11), Score::PINS(1));
+        let frame = Frame::new(Score::PINS(10), Score::EMPTY);
         assert!(frame.is_strike());
+        assert!(!Frame::new(Score::PINS(11), Score::EMPTY).is_strike());
     }
 
     #[test]
@@ -161,4 +224,21 @@ mod tests {
         let frame = Frame::spare(9);
         assert_eq!(frame.value(), 10);
     }
+
+    #[test]
+    fn leave() {
+        let frame = Frame::new(Score::PINSET(PinSet::from_pins(&[1, 2, 3, 4, 5, 6, 8, 9])), Score::PINS(1));
+
+        assert_eq!(frame.leave(), Some(PinSet::from_pins(&[7, 10])));
+        assert_eq!(frame.value(), 9);
+        assert_eq!(Frame::strike().leave(), Some(PinSet::empty()));
+        assert_eq!(Frame::spare(9).leave(), None);
+    }
+
+    #[test]
+    fn shots() {
+        assert_eq!(Frame::strike().shots(), vec![10]);
+        assert_eq!(Frame::spare(9).shots(), vec![9, 1]);
+        assert_eq!(Frame::with_bonus(Score::STRIKE, Score::PINS(9), Score::SPARE).shots(), vec![10, 9, 1]);
+    }
 }
\ No newline at end of file
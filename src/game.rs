@@ -1,6 +1,6 @@
 use std::{fmt, slice::{Iter, IterMut}, vec::IntoIter};
 
-use crate::{Frame, ScoreProvider, TenPinScoreProvider};
+use crate::{BoxStyle, Frame, FrameScore, Scoresheet, ScoreProvider, TenPinScoreProvider};
 
 /// Contains the data for a bowling game.
 #[derive(Clone, Debug, Default, Hash)]
@@ -12,6 +12,9 @@ pub struct Game {
 }
 
 impl Game {
+    /// The most frames a [Game] can have and still pass [Game::validate].
+    pub const MAX_FRAME_COUNT: usize = 100;
+
     /// Create a new [Game].
     pub fn new(frames: Vec<Frame>) -> Self {
         Self {
@@ -28,6 +31,11 @@ impl Game {
         }
     }
 
+    /// Get the [Frame]s of the [Game].
+    pub fn frames(&self) -> &[Frame] {
+        &self.frames
+    }
+
     /// Returns an iterator for the [Frame]s of the [Game].
     pub fn iter(&self) -> Iter<'_, Frame> {
         self.frames.iter()
@@ -38,11 +46,6 @@ impl Game {
         self.frames.iter_mut()
     }
 
-    /// Creates a consuming iterator for the [Frame]s of the [Game].
-    pub fn into_iter(self) -> IntoIter<Frame> {
-        self.frames.into_iter()
-    }
-
     /// Add a [Frame] to the [Game].
     pub fn add_frame(&mut self, frame: Frame) {
         self.frames.push(frame);
@@ -63,14 +66,21 @@ impl Game {
         self.frames[index] = frame;
     }
 
-    /// Check if the [Game] is perfect (300).
+    /// Check if the [Game] is perfect using a [ScoreProvider].
+    ///
+    /// A [Game] with no frames, or with frames still to be bowled, isn't perfect.
+    pub fn is_perfect_with_provider(&self, provider: impl ScoreProvider) -> bool {
+        self.frame_count > 0 && self.frames.len() >= self.frame_count && provider.score(self) == provider.perfect(self)
+    }
+
+    /// Check if the [Game] is perfect (300) using the [TenPinScoreProvider].
     pub fn is_perfect(&self) -> bool {
-        self.score() == 300
+        self.is_perfect_with_provider(TenPinScoreProvider)
     }
 
     /// Calculate the score for each [Frame] of the [Game] using a [ScoreProvider].
     pub fn computed_with_provider(&self, provider: impl ScoreProvider) -> Vec<usize> {
-        provider.computed(&self)
+        provider.computed(self)
     }
 
     /// Calculate the score for each [Frame] of the [Game] using the [TenPinScoreProvider].
@@ -78,9 +88,19 @@ impl Game {
         self.computed_with_provider(TenPinScoreProvider)
     }
 
+    /// Calculate the [FrameScore] for each [Frame] of the [Game] using a [ScoreProvider].
+    pub fn frame_scores_with_provider(&self, provider: impl ScoreProvider) -> Vec<FrameScore> {
+        provider.frame_scores(self)
+    }
+
+    /// Calculate the [FrameScore] for each [Frame] of the [Game] using the [TenPinScoreProvider].
+    pub fn frame_scores(&self) -> Vec<FrameScore> {
+        self.frame_scores_with_provider(TenPinScoreProvider)
+    }
+
     /// Caculate the current score for the [Game] using a [ScoreProvider].
     pub fn score_with_provider(&self, provider: impl ScoreProvider) -> usize {
-        provider.score(&self)
+        provider.score(self)
     }
 
     /// Caculate the current score for the [Game] using the [TenPinScoreProvider].
@@ -90,7 +110,7 @@ impl Game {
 
     /// Calculate the maximum score achievable for the [Game] using a [ScoreProvider].
     pub fn max_with_provider(&self, provider: impl ScoreProvider) -> usize {
-        provider.max(&self, &provider)
+        provider.max(self, &provider)
     }
 
     /// Calculate the maximum score achievable for the [Game] using the [TenPinScoreProvider].
@@ -99,10 +119,38 @@ impl Game {
     }
 }
 
-// TODO: fix formatting for frames
+impl IntoIterator for Game {
+    type Item = Frame;
+    type IntoIter = IntoIter<Frame>;
+
+    /// Creates a consuming iterator for the [Frame]s of the [Game].
+    fn into_iter(self) -> Self::IntoIter {
+        self.frames.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Game {
+    type Item = &'a Frame;
+    type IntoIter = Iter<'a, Frame>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.frames.iter()
+    }
+}
+
 impl fmt::Display for Game {
+    /// Draw the [Game] as a [Scoresheet].
+    ///
+    /// The alternate flag (`{:#}`) draws it with Unicode box-drawing characters, and a width (`{:80}`) limits the width of each line.
     fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(formatter, "Game ({} frame{}) [\n{:#?}\n]", self.frame_count, if self.frame_count == 1 { "" } else { "s" }, self.frames)
+        let style = if formatter.alternate() { BoxStyle::Unicode } else { BoxStyle::Ascii };
+
+        let sheet = Scoresheet {
+            width: formatter.width(),
+            ..Scoresheet::new(style)
+        };
+
+        write!(formatter, "{}", sheet.render(self))
     }
 }
 
@@ -225,6 +273,12 @@ mod tests {
         assert_eq!(game.score(), 50);
     }
 
+    #[test]
+    fn not_perfect() {
+        assert!(!Game::with_frame_count(0, vec![]).is_perfect());
+        assert!(!Game::new(vec![Frame::strike(); 9]).is_perfect());
+    }
+
     #[test]
     fn max_300() {
         let game = Game::new(vec![]);
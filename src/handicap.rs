@@ -0,0 +1,114 @@
+use crate::Series;
+
+/// How a [HandicapRule] rounds a handicap that isn't a whole number of pins.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Rounding {
+    /// Drop any fraction of a pin.
+    #[default]
+    Down,
+    /// Round to the nearest pin, with half a pin rounded up.
+    Nearest,
+    /// Round any fraction of a pin up to a whole pin.
+    Up
+}
+
+/// A percentage-of-difference handicap rule, such as 90% of 220.
+///
+/// The handicap for a [Game](crate::Game) is `percent` of the difference between `basis` and the bowler's average,
+/// rounded with `rounding` and capped at `max`.
+/// Bowlers averaging at or above `basis` get no handicap.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct HandicapRule {
+    /// The score the handicap is measured from.
+    pub basis: usize,
+    /// The percentage of the difference from `basis` given as handicap.
+    pub percent: usize,
+    /// The highest handicap for a single [Game](crate::Game), if there is one.
+    pub max: Option<usize>,
+    /// How a handicap that isn't a whole number of pins is rounded.
+    pub rounding: Rounding
+}
+
+impl HandicapRule {
+    /// Create a new [HandicapRule], with no maximum, that rounds down.
+    pub fn new(basis: usize, percent: usize) -> Self {
+        Self {
+            basis,
+            percent,
+            max: None,
+            rounding: Rounding::Down
+        }
+    }
+
+    /// Calculate the handicap for a single [Game](crate::Game) from a bowler's average.
+    pub fn game_handicap(&self, average: usize) -> usize {
+        let scaled = self.basis.saturating_sub(average) * self.percent;
+
+        let handicap = match self.rounding {
+            Rounding::Down => scaled / 100,
+            Rounding::Nearest => (scaled + 50) / 100,
+            Rounding::Up => scaled.div_ceil(100)
+        };
+
+        self.max.map_or(handicap, |max| handicap.min(max))
+    }
+
+    /// Calculate the handicap for a series of `games` [Game](crate::Game)s from a bowler's average.
+    pub fn series_handicap(&self, average: usize, games: usize) -> usize {
+        self.game_handicap(average) * games
+    }
+
+    /// Add the handicap for a bowler's average to a scratch score, such as from [Game::score](crate::Game::score).
+    pub fn apply(&self, score: usize, average: usize) -> usize {
+        score + self.game_handicap(average)
+    }
+
+    /// Set the handicap of a [Series] from a bowler's average.
+    pub fn apply_to_series(&self, series: &mut Series, average: usize) {
+        series.handicap = self.game_handicap(average);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Frame, Game, HandicapRule, Rounding, Score, Series};
+
+    #[test]
+    fn percentage_of_difference() {
+        let rule = HandicapRule::new(220, 90);
+
+        assert_eq!(rule.game_handicap(180), 36);
+        assert_eq!(rule.game_handicap(183), 33);
+        assert_eq!(rule.game_handicap(220), 0);
+        assert_eq!(rule.game_handicap(240), 0);
+    }
+
+    #[test]
+    fn rounding() {
+        let rule = HandicapRule::new(220, 90);
+
+        assert_eq!(HandicapRule { rounding: Rounding::Up, ..rule }.game_handicap(183), 34);
+        assert_eq!(HandicapRule { rounding: Rounding::Nearest, ..rule }.game_handicap(183), 33);
+        assert_eq!(HandicapRule { rounding: Rounding::Nearest, ..rule }.game_handicap(185), 32);
+    }
+
+    #[test]
+    fn max() {
+        let rule = HandicapRule { max: Some(30), ..HandicapRule::new(220, 90) };
+
+        assert_eq!(rule.game_handicap(150), 30);
+        assert_eq!(rule.series_handicap(150, 3), 90);
+    }
+
+    #[test]
+    fn apply() {
+        let rule = HandicapRule::new(220, 90);
+        let game = Game::new(vec![Frame::new(Score::PINS(7), Score::PINS(2)); 10]);
+        let mut series = Series::new(vec![game.clone(); 3]);
+
+        assert_eq!(rule.apply(game.score(), 180), 126);
+
+        rule.apply_to_series(&mut series, 180);
+        assert_eq!(series.handicap_total(), 378);
+    }
+}
\ No newline at end of file
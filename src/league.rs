@@ -0,0 +1,356 @@
+use std::cmp::Ordering;
+
+use crate::{HandicapRule, ScoreProvider, Series, TenPinScoreProvider};
+
+/// A bowler in a [League].
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Bowler {
+    /// The name of the [Bowler].
+    pub name: String,
+    /// The average of the [Bowler], used for handicap.
+    pub average: usize
+}
+
+impl Bowler {
+    /// Create a new [Bowler].
+    pub fn new(name: impl Into<String>, average: usize) -> Self {
+        Self {
+            name: name.into(),
+            average
+        }
+    }
+}
+
+/// A team in a [League].
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Team {
+    /// The name of the [Team].
+    pub name: String,
+    /// The [Bowler]s of the [Team], in lineup order.
+    pub bowlers: Vec<Bowler>
+}
+
+impl Team {
+    /// Create a new [Team].
+    pub fn new(name: impl Into<String>, bowlers: Vec<Bowler>) -> Self {
+        Self {
+            name: name.into(),
+            bowlers
+        }
+    }
+}
+
+/// How points are awarded for a [Matchup].
+///
+/// The team with the higher total wins each game and the series, and tied teams split the points.
+/// With a [HandicapRule], each [Bowler]'s handicap is added to their games before they are compared.
+/// The handicap is the [Series] handicap when it has one, or is worked out from the [Bowler]'s average.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct PointsRule {
+    /// The points for winning a single game.
+    pub per_game: usize,
+    /// The points for winning the series.
+    pub series: usize,
+    /// The [HandicapRule] for handicap leagues, or `None` for scratch leagues.
+    pub handicap: Option<HandicapRule>
+}
+
+impl PointsRule {
+    /// Create a new scratch [PointsRule].
+    pub fn new(per_game: usize, series: usize) -> Self {
+        Self {
+            per_game,
+            series,
+            handicap: None
+        }
+    }
+
+    /// Create a new [PointsRule] for a handicap league.
+    pub fn with_handicap(per_game: usize, series: usize, handicap: HandicapRule) -> Self {
+        Self {
+            per_game,
+            series,
+            handicap: Some(handicap)
+        }
+    }
+}
+
+/// Two [Team]s bowling against each other for a week.
+#[derive(Clone, Debug, Default, Hash)]
+pub struct Matchup {
+    /// The index of the home [Team] in the [League].
+    pub home: usize,
+    /// The index of the away [Team] in the [League].
+    pub away: usize,
+    /// The [Series] of each home [Bowler], in lineup order.
+    pub home_series: Vec<Series>,
+    /// The [Series] of each away [Bowler], in lineup order.
+    pub away_series: Vec<Series>
+}
+
+impl Matchup {
+    /// Create a new [Matchup].
+    pub fn new(home: usize, away: usize, home_series: Vec<Series>, away_series: Vec<Series>) -> Self {
+        Self {
+            home,
+            away,
+            home_series,
+            away_series
+        }
+    }
+}
+
+/// The result of a [Matchup] for one [Team].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct TeamResult {
+    /// The index of the [Team] in the [League].
+    pub team: usize,
+    /// The points won by the [Team].
+    pub points: f64,
+    /// The scratch pins of the [Team].
+    pub scratch_pins: usize,
+    /// The pins of the [Team], with handicap.
+    pub total_pins: usize,
+    /// The highest team game, with handicap.
+    pub high_game: usize
+}
+
+/// A row of the [League] standings.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Standing {
+    /// The index of the [Team] in the [League].
+    pub team: usize,
+    /// The points won by the [Team].
+    pub points_won: f64,
+    /// The points won by the [Team]'s opponents.
+    pub points_lost: f64,
+    /// The scratch pins of the [Team].
+    pub scratch_pins: usize,
+    /// The pins of the [Team], with handicap.
+    pub total_pins: usize,
+    /// The highest team game, with handicap.
+    pub high_game: usize
+}
+
+/// Contains the [Team]s and weekly [Matchup]s of a league.
+#[derive(Clone, Debug, Default)]
+pub struct League {
+    /// The [Team]s of the [League].
+    pub teams: Vec<Team>,
+    /// How points are awarded for each [Matchup].
+    pub rule: PointsRule,
+    /// The [Matchup]s of each week.
+    weeks: Vec<Vec<Matchup>>
+}
+
+impl League {
+    /// Create a new [League].
+    pub fn new(teams: Vec<Team>, rule: PointsRule) -> Self {
+        Self {
+            teams,
+            rule,
+            weeks: vec![]
+        }
+    }
+
+    /// Add a week of [Matchup]s to the [League].
+    pub fn add_week(&mut self, matchups: Vec<Matchup>) {
+        self.weeks.push(matchups);
+    }
+
+    /// Get a week of [Matchup]s from the [League].
+    pub fn week(&self, index: usize) -> Option<&[Matchup]> {
+        self.weeks.get(index).map(Vec::as_slice)
+    }
+
+    /// Calculate the scratch and handicap total of each game for a [Team].
+    fn team_games(&self, team: usize, series: &[Series], provider: impl ScoreProvider) -> Vec<(usize, usize)> {
+        let mut games: Vec<(usize, usize)> = vec![];
+
+        for (i, bowler_series) in series.iter().enumerate() {
+            let average = self.teams.get(team).and_then(|team| team.bowlers.get(i)).map(|bowler| bowler.average);
+            let handicap = match self.rule.handicap {
+                Some(_) if bowler_series.handicap > 0 => bowler_series.handicap,
+                Some(rule) => average.map_or(0, |average| rule.game_handicap(average)),
+                None => 0
+            };
+
+            for (game, score) in bowler_series.scores_with_provider(provider).into_iter().enumerate() {
+                if games.len() <= game {
+                    games.resize(game + 1, (0, 0));
+                }
+
+                games[game].0 += score;
+                games[game].1 += score + handicap;
+            }
+        }
+
+        games
+    }
+
+    /// Calculate the [TeamResult]s of a [Matchup] using a [ScoreProvider], home [Team] first.
+    pub fn matchup_result_with_provider(&self, matchup: &Matchup, provider: impl ScoreProvider) -> [TeamResult; 2] {
+        let home_games = self.team_games(matchup.home, &matchup.home_series, provider);
+        let away_games = self.team_games(matchup.away, &matchup.away_series, provider);
+
+        let mut home = TeamResult { team: matchup.home, ..TeamResult::default() };
+        let mut away = TeamResult { team: matchup.away, ..TeamResult::default() };
+
+        for game in 0..home_games.len().max(away_games.len()) {
+            let (home_scratch, home_total) = home_games.get(game).copied().unwrap_or_default();
+            let (away_scratch, away_total) = away_games.get(game).copied().unwrap_or_default();
+
+            award(&mut home, &mut away, home_total, away_total, self.rule.per_game);
+
+            home.scratch_pins += home_scratch;
+            home.total_pins += home_total;
+            home.high_game = home.high_game.max(home_total);
+            away.scratch_pins += away_scratch;
+            away.total_pins += away_total;
+            away.high_game = away.high_game.max(away_total);
+        }
+
+        let (home_total, away_total) = (home.total_pins, away.total_pins);
+        award(&mut home, &mut away, home_total, away_total, self.rule.series);
+
+        [home, away]
+    }
+
+    /// Calculate the [TeamResult]s of a [Matchup] using the [TenPinScoreProvider], home [Team] first.
+    pub fn matchup_result(&self, matchup: &Matchup) -> [TeamResult; 2] {
+        self.matchup_result_with_provider(matchup, TenPinScoreProvider)
+    }
+
+    /// Calculate the standings of the [League] using a [ScoreProvider].
+    ///
+    /// [Team]s are sorted by points won, then total pins, then high game.
+    pub fn standings_with_provider(&self, provider: impl ScoreProvider) -> Vec<Standing> {
+        let mut standings: Vec<Standing> = (0..self.teams.len()).map(|team| Standing { team, ..Standing::default() }).collect();
+
+        for matchup in self.weeks.iter().flatten() {
+            let results = self.matchup_result_with_provider(matchup, provider);
+
+            for (result, opponent) in [(results[0], results[1]), (results[1], results[0])] {
+                if let Some(standing) = standings.get_mut(result.team) {
+                    standing.points_won += result.points;
+                    standing.points_lost += opponent.points;
+                    standing.scratch_pins += result.scratch_pins;
+                    standing.total_pins += result.total_pins;
+                    standing.high_game = standing.high_game.max(result.high_game);
+                }
+            }
+        }
+
+        standings.sort_by(|a, b| {
+            b.points_won.partial_cmp(&a.points_won).unwrap_or(Ordering::Equal)
+                .then(b.total_pins.cmp(&a.total_pins))
+                .then(b.high_game.cmp(&a.high_game))
+                .then(a.team.cmp(&b.team))
+        });
+
+        standings
+    }
+
+    /// Calculate the standings of the [League] using the [TenPinScoreProvider].
+    ///
+    /// [Team]s are sorted by points won, then total pins, then high game.
+    pub fn standings(&self) -> Vec<Standing> {
+        self.standings_with_provider(TenPinScoreProvider)
+    }
+}
+
+/// Award `points` to the [TeamResult] with the higher total, or split them on a tie.
+fn award(home: &mut TeamResult, away: &mut TeamResult, home_total: usize, away_total: usize, points: usize) {
+    let points = points as f64;
+
+    match home_total.cmp(&away_total) {
+        Ordering::Greater => home.points += points,
+        Ordering::Less => away.points += points,
+        Ordering::Equal => {
+            home.points += points / 2.0;
+            away.points += points / 2.0;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Bowler, Frame, Game, HandicapRule, League, Matchup, PointsRule, Score, Series, Team};
+
+    /// Create a [Series] of open games, each worth ten times its pins.
+    fn series(pins: &[usize]) -> Series {
+        pins.iter().map(|pins| Game::new(vec![Frame::new(Score::PINS(*pins), Score::PINS(0)); 10])).collect()
+    }
+
+    fn league(rule: PointsRule) -> League {
+        let mut league = League::new(vec![
+            Team::new("Pin Pals", vec![Bowler::new("Homer", 180), Bowler::new("Otto", 150)]),
+            Team::new("Holy Rollers", vec![Bowler::new("Ned", 200), Bowler::new("Rod", 190)])
+        ], rule);
+
+        league.add_week(vec![Matchup::new(0, 1, vec![series(&[9, 8, 7]), series(&[5, 5, 5])], vec![series(&[9, 6, 7]), series(&[5, 5, 4])])]);
+        league
+    }
+
+    #[test]
+    fn scratch_points() {
+        let league = league(PointsRule::new(1, 1));
+        let [home, away] = league.matchup_result(&league.week(0).unwrap()[0]);
+
+        assert_eq!(home.points, 3.5);
+        assert_eq!(away.points, 0.5);
+        assert_eq!(home.scratch_pins, 390);
+        assert_eq!(away.total_pins, 360);
+    }
+
+    #[test]
+    fn handicap_points() {
+        let league = league(PointsRule::with_handicap(1, 1, HandicapRule::new(220, 90)));
+        let [home, away] = league.matchup_result(&league.week(0).unwrap()[0]);
+
+        assert_eq!(home.points, 4.0);
+        assert_eq!(away.points, 0.0);
+        assert_eq!(home.total_pins, 390 + 99 * 3);
+        assert_eq!(home.high_game, 239);
+        assert_eq!(away.scratch_pins, 360);
+    }
+
+    #[test]
+    fn series_handicap() {
+        let mut handicap = league(PointsRule::with_handicap(1, 1, HandicapRule::new(220, 90)));
+        handicap.weeks[0][0].away_series[0].handicap = 50;
+
+        let [home, away] = handicap.matchup_result(&handicap.week(0).unwrap()[0]);
+
+        // Ned's series handicap of 50 replaces the 18 from his average
+        assert_eq!(away.total_pins, 360 + (50 + 27) * 3);
+        assert_eq!(home.total_pins, 390 + 99 * 3);
+
+        let mut scratch = league(PointsRule::new(1, 1));
+        scratch.weeks[0][0].away_series[0].handicap = 50;
+        assert_eq!(scratch.matchup_result(&scratch.week(0).unwrap()[0])[1].total_pins, 360);
+    }
+
+    #[test]
+    fn standings() {
+        let standings = league(PointsRule::new(2, 2)).standings();
+
+        assert_eq!(standings[0].team, 0);
+        assert_eq!(standings[0].points_won, 7.0);
+        assert_eq!(standings[0].points_lost, 1.0);
+        assert_eq!(standings[1].team, 1);
+    }
+
+    #[test]
+    fn tiebreakers() {
+        let mut league = League::new(vec![Team::new("Away", vec![Bowler::new("A", 150)]), Team::new("Home", vec![Bowler::new("B", 150)])], PointsRule::new(1, 1));
+        league.add_week(vec![Matchup::new(1, 0, vec![series(&[9, 7])], vec![series(&[8, 8])])]);
+
+        let standings = league.standings();
+
+        // Both teams split the points and the pins, but "Home" has the higher game
+        assert_eq!(standings[0].points_won, standings[1].points_won);
+        assert_eq!(standings[0].total_pins, standings[1].total_pins);
+        assert_eq!(standings[0].team, 1);
+    }
+}
\ No newline at end of file
@@ -3,14 +3,58 @@
 
 #[macro_use]
 mod macros;
+mod baker;
+mod candlepin;
+mod current_frame;
+mod duckpin;
+mod five_pin;
+#[cfg(test)]
+mod fixtures;
 mod frame;
 mod game;
+mod handicap;
+mod league;
+mod live_game;
+mod no_tap;
+mod notation;
+mod recap;
 mod score;
 mod score_provider;
+mod scoresheet;
+mod scotch_doubles;
+mod series;
+#[cfg(feature = "serde")]
+mod serialize;
 mod parse;
+mod peterson;
+mod pin_set;
+mod split;
+mod stats;
+mod svg;
+mod three_ball;
+mod tournament;
 
+pub use baker::*;
+pub use candlepin::*;
+pub use current_frame::*;
+pub use duckpin::*;
+pub use five_pin::*;
 pub use frame::*;
 pub use game::*;
+pub use handicap::*;
+pub use league::*;
+pub use live_game::*;
+pub use no_tap::*;
+pub use recap::*;
 pub use score::*;
 pub use score_provider::*;
-pub use parse::*;
\ No newline at end of file
+pub use scoresheet::*;
+pub use scotch_doubles::*;
+pub use series::*;
+pub use parse::*;
+pub use peterson::*;
+pub use pin_set::*;
+pub use split::*;
+pub use stats::*;
+pub use svg::*;
+pub use tournament::*;
\ No newline at end of file
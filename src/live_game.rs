@@ -0,0 +1,395 @@
+use std::{error::Error, fmt};
+
+use crate::{Frame, FrameScore, Game, PinSet, Score, ScoreProvider, TenPinScoreProvider};
+
+/// An error returned when a shot can't be added to a [LiveGame].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum RollError {
+    /// The [LiveGame] is already complete.
+    GameComplete,
+    /// The shot knocked down more pins than were standing.
+    TooManyPins {
+        /// The number of pins the shot knocked down.
+        pins: usize,
+        /// The number of pins that were standing.
+        standing: usize
+    },
+    /// The shot knocked down a pin that had already fallen.
+    PinDown {
+        /// The number of the pin.
+        pin: usize
+    }
+}
+
+impl fmt::Display for RollError {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RollError::GameComplete => write!(formatter, "the game is already complete"),
+            RollError::TooManyPins { pins, standing } => write!(formatter, "{} pins is more than the {} pins standing", pins, standing),
+            RollError::PinDown { pin } => write!(formatter, "pin {} has already been knocked down", pin)
+        }
+    }
+}
+
+impl Error for RollError {}
+
+/// A [Game] that is scored one shot at a time.
+#[derive(Clone, Debug, Hash)]
+pub struct LiveGame {
+    /// The [Game] being bowled.
+    game: Game,
+    /// The index of the current [Frame].
+    frame: usize,
+    /// The index of the current shot in the [Frame].
+    ball: usize,
+    /// The number of pins standing.
+    standing: usize,
+    /// Whether the next shot is the first one at a full rack.
+    fresh: bool,
+    /// The pins standing, if every shot at the current rack recorded which pins were knocked down.
+    standing_pins: Option<PinSet>,
+    /// Whether the final [Frame] has bonus shots.
+    bonus: bool,
+    /// Whether the [LiveGame] is complete.
+    complete: bool
+}
+
+impl LiveGame {
+    /// Create a new [LiveGame].
+    pub fn new() -> Self {
+        Self::with_frame_count(10)
+    }
+
+    /// Create a new [LiveGame] with a custom frame count.
+    pub fn with_frame_count(frame_count: usize) -> Self {
+        Self {
+            game: Game::with_frame_count(frame_count, vec![]),
+            frame: 0,
+            ball: 0,
+            standing: 10,
+            fresh: true,
+            standing_pins: Some(PinSet::all()),
+            bonus: true,
+            complete: frame_count == 0
+        }
+    }
+
+    /// Create a new [LiveGame] where the final [Frame] has no bonus shots, as in [CurrentFrameScoreProvider](crate::CurrentFrameScoreProvider) scoring.
+    pub fn without_bonus() -> Self {
+        Self {
+            bonus: false,
+            ..Self::new()
+        }
+    }
+
+    /// Add a shot to the [LiveGame], moving on to the next [Frame] when needed.
+    pub fn roll(&mut self, pins: usize) -> Result<(), RollError> {
+        self.roll_score(pins, Score::PINS(pins))
+    }
+
+    /// Add a fouled shot to the [LiveGame].
+    ///
+    /// No pins count, and any pins knocked down are reset, so a fouled first ball can't be followed by a strike.
+    pub fn foul(&mut self) -> Result<(), RollError> {
+        self.roll_score(0, Score::FOUL)
+    }
+
+    /// Add a shot to the [LiveGame] using the pins it knocked down, so the [Frame] can record its leave.
+    pub fn roll_pins(&mut self, pins: PinSet) -> Result<(), RollError> {
+        if let Some(standing) = self.standing_pins
+            && let Some(pin) = pins.difference(standing).iter().next() {
+            return Err(RollError::PinDown { pin });
+        }
+
+        self.roll_score(pins.len(), Score::PINSET(pins))
+    }
+
+    /// Add a shot to the [LiveGame], recorded as `shot` unless it was a strike or spare.
+    fn roll_score(&mut self, pins: usize, shot: Score) -> Result<(), RollError> {
+        if self.complete {
+            return Err(RollError::GameComplete);
+        }
+
+        if pins > self.standing {
+            return Err(RollError::TooManyPins { pins, standing: self.standing });
+        }
+
+        let final_frame = self.frame == self.game.frame_count - 1;
+        let bonus_frame = final_frame && self.bonus;
+
+        let score = if shot == Score::FOUL {
+            Score::FOUL
+        } else if pins == 10 && self.fresh {
+            Score::STRIKE
+        } else if pins == self.standing && !self.fresh {
+            Score::SPARE
+        } else {
+            shot
+        };
+
+        if self.ball == 0 {
+            self.game.add_frame(Frame::new(score, Score::EMPTY));
+        } else {
+            let mut frame = *self.game.frame(self.frame).unwrap();
+
+            if self.ball == 1 {
+                frame.second = score;
+            } else {
+                frame.bonus = Some(score);
+            }
+
+            self.game.set_frame(self.frame, frame);
+        }
+
+        self.standing -= pins;
+        self.fresh = self.standing == 0;
+        self.standing_pins = match shot {
+            Score::PINSET(pins) => self.standing_pins.map(|standing| standing.difference(pins)),
+            // Pins knocked down by a foul are reset
+            Score::FOUL => self.standing_pins,
+            _ => None
+        };
+
+        if self.fresh {
+            self.standing = 10;
+            self.standing_pins = Some(PinSet::all());
+        }
+
+        let frame = *self.game.frame(self.frame).unwrap();
+
+        if bonus_frame && (frame.is_strike() || frame.is_spare()) && frame.bonus.is_none() {
+            // Mark the bonus shot as earned, so partial scores count every shot
+            let mut frame = frame;
+            frame.bonus = Some(Score::EMPTY);
+            self.game.set_frame(self.frame, frame);
+        }
+
+        let frame_done = if bonus_frame {
+            self.ball == 2 || (self.ball == 1 && !frame.is_strike() && !frame.is_spare())
+        } else {
+            self.ball == 1 || score == Score::STRIKE
+        };
+
+        if !frame_done {
+            self.ball += 1;
+        } else if final_frame {
+            self.complete = true;
+        } else {
+            self.frame += 1;
+            self.ball = 0;
+            self.standing = 10;
+            self.fresh = true;
+            self.standing_pins = Some(PinSet::all());
+        }
+
+        Ok(())
+    }
+
+    /// Check if the [LiveGame] is complete.
+    pub fn is_complete(&self) -> bool {
+        self.complete
+    }
+
+    /// The index of the [Frame] the next shot belongs to, or `None` if the [LiveGame] is complete.
+    pub fn current_frame(&self) -> Option<usize> {
+        (!self.complete).then_some(self.frame)
+    }
+
+    /// The index of the next shot in the current [Frame], or `None` if the [LiveGame] is complete.
+    pub fn current_ball(&self) -> Option<usize> {
+        (!self.complete).then_some(self.ball)
+    }
+
+    /// The number of pins standing for the next shot.
+    pub fn standing(&self) -> usize {
+        self.standing
+    }
+
+    /// The pins standing for the next shot, if every shot at the current rack recorded which pins were knocked down.
+    pub fn standing_pins(&self) -> Option<PinSet> {
+        self.standing_pins
+    }
+
+    /// Get the [Game] being bowled.
+    pub fn game(&self) -> &Game {
+        &self.game
+    }
+
+    /// Calculate the score for each [Frame] so far using the [TenPinScoreProvider].
+    pub fn computed(&self) -> Vec<usize> {
+        self.game.computed_with_provider(TenPinScoreProvider)
+    }
+
+    /// Calculate the [FrameScore] for each [Frame] so far using the [TenPinScoreProvider].
+    pub fn frame_scores(&self) -> Vec<FrameScore> {
+        self.game.frame_scores_with_provider(TenPinScoreProvider)
+    }
+
+    /// Caculate the current score using the [TenPinScoreProvider].
+    pub fn score(&self) -> usize {
+        TenPinScoreProvider.score(&self.game)
+    }
+}
+
+impl Default for LiveGame {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<LiveGame> for Game {
+    fn from(live: LiveGame) -> Self {
+        live.game
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CurrentFrameScoreProvider, LiveGame, PinSet, RollError};
+
+    #[test]
+    fn perfect_game() {
+        let mut live = LiveGame::new();
+
+        for _ in 0..12 {
+            live.roll(10).unwrap();
+        }
+
+        assert!(live.is_complete());
+        assert_eq!(live.score(), 300);
+        assert_eq!(live.roll(10), Err(RollError::GameComplete));
+    }
+
+    #[test]
+    fn advances_frames() {
+        let mut live = LiveGame::new();
+
+        live.roll(7).unwrap();
+        assert_eq!((live.current_frame(), live.current_ball(), live.standing()), (Some(0), Some(1), 3));
+
+        live.roll(3).unwrap();
+        live.roll(10).unwrap();
+        assert_eq!((live.current_frame(), live.current_ball()), (Some(2), Some(0)));
+    }
+
+    #[test]
+    fn partial_scores() {
+        let mut live = LiveGame::new();
+
+        live.roll(10).unwrap();
+        live.roll(10).unwrap();
+        live.roll(7).unwrap();
+        assert_eq!(live.computed(), vec![27, 44, 51]);
+        assert_eq!(live.frame_scores()[0].running, Some(27));
+        assert_eq!(live.frame_scores()[1].running, None);
+
+        live.roll(2).unwrap();
+        assert_eq!(live.score(), 55);
+    }
+
+    #[test]
+    fn too_many_pins() {
+        let mut live = LiveGame::new();
+
+        live.roll(7).unwrap();
+        assert_eq!(live.roll(4), Err(RollError::TooManyPins { pins: 4, standing: 3 }));
+    }
+
+    #[test]
+    fn final_frame_bonus() {
+        let mut live = LiveGame::new();
+
+        for _ in 0..9 {
+            live.roll(0).unwrap();
+            live.roll(0).unwrap();
+        }
+
+        live.roll(9).unwrap();
+        live.roll(1).unwrap();
+        assert_eq!((live.current_frame(), live.current_ball(), live.standing()), (Some(9), Some(2), 10));
+
+        live.roll(10).unwrap();
+        assert!(live.is_complete());
+        assert_eq!(live.score(), 20);
+    }
+
+    #[test]
+    fn final_frame_open() {
+        let mut live = LiveGame::new();
+
+        for _ in 0..9 {
+            live.roll(10).unwrap();
+        }
+
+        live.roll(7).unwrap();
+        live.roll(1).unwrap();
+        assert!(live.is_complete());
+        assert_eq!(live.score(), 263);
+    }
+
+    #[test]
+    fn final_frame_strike_then_pins() {
+        let mut live = LiveGame::new();
+
+        for _ in 0..10 {
+            live.roll(10).unwrap();
+        }
+
+        live.roll(7).unwrap();
+        assert_eq!(live.roll(4), Err(RollError::TooManyPins { pins: 4, standing: 3 }));
+
+        live.roll(3).unwrap();
+        assert_eq!(live.game().to_notation(), "X X X X X X X X X X7/");
+        assert_eq!(live.score(), 287);
+    }
+
+    #[test]
+    fn without_bonus() {
+        let mut live = LiveGame::without_bonus();
+
+        for _ in 0..10 {
+            live.roll(10).unwrap();
+        }
+
+        assert!(live.is_complete());
+        assert_eq!(live.game().score_with_provider(CurrentFrameScoreProvider), 300);
+    }
+
+    #[test]
+    fn roll_pins() {
+        let mut live = LiveGame::new();
+
+        live.roll_pins(PinSet::from_pins(&[1, 2, 3, 4, 5, 6, 8, 9])).unwrap();
+        assert_eq!(live.standing_pins(), Some(PinSet::from_pins(&[7, 10])));
+        assert_eq!(live.roll_pins(PinSet::from_pins(&[1])), Err(RollError::PinDown { pin: 1 }));
+
+        live.roll_pins(PinSet::from_pins(&[7])).unwrap();
+        assert_eq!(live.game().frame(0).unwrap().leave(), Some(PinSet::from_pins(&[7, 10])));
+        assert_eq!(live.score(), 9);
+        assert_eq!(live.standing_pins(), Some(PinSet::all()));
+    }
+
+    #[test]
+    fn roll_pins_after_count() {
+        let mut live = LiveGame::new();
+
+        live.roll(8).unwrap();
+        assert_eq!(live.standing_pins(), None);
+
+        live.roll_pins(PinSet::from_pins(&[7, 10])).unwrap();
+        assert_eq!(live.score(), 10);
+    }
+
+    #[test]
+    fn foul_then_ten() {
+        let mut live = LiveGame::new();
+
+        live.foul().unwrap();
+        assert_eq!(live.standing(), 10);
+
+        live.roll(10).unwrap();
+        live.roll(5).unwrap();
+        assert_eq!(live.game().to_notation(), "F/ 5");
+        assert_eq!(live.score(), 20);
+    }
+}
\ No newline at end of file
@@ -150,6 +150,26 @@ macro_rules! parse_raw_score {
     });
 }
 
+/// Implement [ScoreProvider](crate::ScoreProvider) for a game with three balls per frame and ten pins worth one point each,
+/// such as candlepin and duckpin.
+macro_rules! three_ball_provider {
+    ($provider:ty) => {
+        impl $crate::ScoreProvider for $provider {
+            fn computed(&self, game: &$crate::Game) -> Vec<usize> {
+                $crate::three_ball::computed(game, 10, $crate::Frame::shots)
+            }
+
+            fn frame_scores(&self, game: &$crate::Game) -> Vec<$crate::FrameScore> {
+                $crate::three_ball::frame_scores(game, 10, $crate::Frame::shots)
+            }
+
+            fn max(&self, game: &$crate::Game, provider: &impl $crate::ScoreProvider) -> usize {
+                $crate::three_ball::max(game, provider, 10, $crate::Frame::shots)
+            }
+        }
+    };
+}
+
 #[cfg(test)]
 mod tests {
     // TODO: add more macro tests
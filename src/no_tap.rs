@@ -0,0 +1,135 @@
+use crate::{Frame, FrameScore, Game, Score, ScoreProvider, TenPinScoreProvider};
+
+/// [ScoreProvider] for no-tap bowling, such as 9-pin no-tap.
+///
+/// Any first ball that knocks down at least `threshold` pins counts as a strike,
+/// and any frame that knocks down at least `threshold` pins in two balls counts as a spare.
+/// Otherwise, the [Game] is scored like classic 10-pin bowling.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct NoTapScoreProvider {
+    /// The number of pins that counts as knocking down every pin.
+    pub threshold: usize
+}
+
+impl NoTapScoreProvider {
+    /// Create a new [NoTapScoreProvider].
+    pub fn new(threshold: usize) -> Self {
+        Self {
+            threshold
+        }
+    }
+
+    /// Convert a [Frame] into its classic 10-pin equivalent, with strikes and spares marked.
+    pub fn normalize(&self, frame: &Frame) -> Frame {
+        let mut scores = vec![];
+        let mut first = None;
+
+        for score in [Some(frame.first), Some(frame.second), frame.bonus].into_iter().flatten() {
+            let pins = match score {
+                Score::EMPTY => break,
+                Score::SPARE => 10 - first.unwrap_or(0),
+                score => score.value()
+            };
+
+            if score == Score::FOUL {
+                // A foul counts for nothing, but still uses up the ball
+                scores.push(Score::FOUL);
+                first = if first.is_some() { None } else { Some(0) };
+            } else if let Some(first_pins) = first {
+                scores.push(if first_pins + pins >= self.threshold { Score::SPARE } else { Score::PINS(pins) });
+                first = None;
+            } else if pins >= self.threshold {
+                scores.push(Score::STRIKE);
+            } else {
+                scores.push(Score::PINS(pins));
+                first = Some(pins);
+            }
+        }
+
+        Frame {
+            first: scores.first().copied().unwrap_or(Score::EMPTY),
+            second: scores.get(1).copied().unwrap_or(Score::EMPTY),
+            bonus: frame.bonus.map(|_| scores.get(2).copied().unwrap_or(Score::EMPTY))
+        }
+    }
+
+    /// Convert every [Frame] of a [Game] into its classic 10-pin equivalent.
+    fn normalize_game(&self, game: &Game) -> Game {
+        Game::with_frame_count(game.frame_count, game.iter().map(|frame| self.normalize(frame)).collect())
+    }
+}
+
+impl Default for NoTapScoreProvider {
+    /// Create a [NoTapScoreProvider] for 9-pin no-tap.
+    fn default() -> Self {
+        Self::new(9)
+    }
+}
+
+impl ScoreProvider for NoTapScoreProvider {
+    fn computed(&self, game: &Game) -> Vec<usize> {
+        TenPinScoreProvider.computed(&self.normalize_game(game))
+    }
+
+    fn frame_scores(&self, game: &Game) -> Vec<FrameScore> {
+        TenPinScoreProvider.frame_scores(&self.normalize_game(game))
+    }
+
+    fn max(&self, game: &Game, provider: &impl ScoreProvider) -> usize {
+        ScoreProvider::max(&TenPinScoreProvider, &self.normalize_game(game), provider)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Frame, Game, NoTapScoreProvider, Score};
+
+    #[test]
+    fn nine_pin_strike() {
+        let provider = NoTapScoreProvider::new(9);
+
+        assert_eq!(provider.normalize(&Frame::new(Score::PINS(9), Score::EMPTY)), Frame::strike());
+        assert_eq!(provider.normalize(&Frame::new(Score::PINS(6), Score::PINS(3))), Frame::spare(6));
+        assert_eq!(provider.normalize(&Frame::new(Score::PINS(6), Score::PINS(2))), Frame::new(Score::PINS(6), Score::PINS(2)));
+    }
+
+    #[test]
+    fn nine_pin_perfect_game() {
+        let mut game = Game::new(vec![Frame::new(Score::PINS(9), Score::EMPTY); 9]);
+        game.add_frame(Frame::with_bonus(Score::PINS(9), Score::PINS(9), Score::PINS(9)));
+
+        assert_eq!(game.score_with_provider(NoTapScoreProvider::new(9)), 300);
+        assert!(game.is_perfect_with_provider(NoTapScoreProvider::new(9)));
+    }
+
+    #[test]
+    fn eight_pin_game() {
+        let game = Game::new(vec![Frame::new(Score::PINS(8), Score::EMPTY), Frame::new(Score::PINS(5), Score::PINS(3)), Frame::new(Score::PINS(4), Score::PINS(3))]);
+        assert_eq!(game.computed_with_provider(NoTapScoreProvider::new(8)), vec![20, 34, 41]);
+    }
+
+    #[test]
+    fn final_frame_spare() {
+        let mut game = Game::new(vec![Frame::strike(); 9]);
+        game.add_frame(Frame::with_bonus(Score::PINS(9), Score::PINS(7), Score::PINS(2)));
+
+        assert_eq!(game.frame_scores_with_provider(NoTapScoreProvider::new(9))[9].base, 20);
+    }
+
+    #[test]
+    fn foul() {
+        let provider = NoTapScoreProvider::new(9);
+
+        assert_eq!(provider.normalize(&Frame::new(Score::FOUL, Score::PINS(9))), Frame::new(Score::FOUL, Score::SPARE));
+        assert_eq!(provider.normalize(&Frame::new(Score::PINS(5), Score::FOUL)), Frame::new(Score::PINS(5), Score::FOUL));
+    }
+
+    #[test]
+    fn max() {
+        let provider = NoTapScoreProvider::new(9);
+
+        assert_eq!(Game::new(vec![]).max_with_provider(provider), 300);
+        assert_eq!(Game::new(vec![Frame::new(Score::PINS(7), Score::EMPTY)]).max_with_provider(provider), 290);
+        assert_eq!(Game::new(vec![Frame::new(Score::PINS(6), Score::PINS(2))]).max_with_provider(provider), 278);
+    }
+}
\ No newline at end of file
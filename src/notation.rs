@@ -0,0 +1,277 @@
+use std::str::FromStr;
+
+use crate::{split, try_parse_score, Frame, Game, ParseError, PinSet, Score};
+
+impl Game {
+    /// Parse a [Game] from scoresheet notation, such as `X 7/ 9- X X 81 -/ X X XX7`.
+    ///
+    /// The following symbols are supported:
+    /// - `X` Strike
+    /// - `/` Spare
+    /// - `-` Gutter
+    /// - `F` Foul
+    /// - `1` to `9` Pins
+    /// - `S` Split, placed before a first ball of `2` to `8`
+    /// - `②` to `⑧` Split, circled the way scoresheets mark them
+    ///
+    /// Notation only records how many pins a split knocked down, so the shot is stored as a [Score]::SPLIT.
+    /// Whitespace and `|` can be used to separate frames, but aren't required.
+    /// Like [try_parse_score](crate::try_parse_score), the [Game] always has ten frames and can be unfinished.
+    pub fn from_notation(notation: &str) -> Result<Self, ParseError> {
+        let mut frames = vec![];
+        let mut scores = vec![];
+        let mut current = vec![];
+        let mut current_scores = vec![];
+        let mut standing = 10;
+        // Whether the next shot is the first one at a full rack
+        let mut fresh = true;
+        // Whether an `S` marked the next shot as a split
+        let mut split = false;
+
+        for symbol in notation.chars() {
+            if symbol.is_whitespace() || symbol == '|' {
+                continue;
+            }
+
+            let frame = frames.len();
+            let shot = current.len();
+
+            let (pins, score) = match symbol {
+                // Splits can only be left by the first shot at a full rack
+                'S' | 's' if fresh && !split => {
+                    split = true;
+                    continue;
+                },
+                '1'..='9' if split => {
+                    let pins = symbol as usize - '0' as usize;
+                    split_score(pins).ok_or(ParseError::InvalidSymbol { frame, shot, symbol })?
+                },
+                _ if split => return Err(ParseError::InvalidSymbol { frame, shot, symbol }),
+                '①'..='⑨' if fresh => {
+                    let pins = symbol as usize - '①' as usize + 1;
+                    split_score(pins).ok_or(ParseError::InvalidSymbol { frame, shot, symbol })?
+                },
+                'X' | 'x' if fresh => (10, Score::STRIKE),
+                '/' if !fresh => (standing, Score::SPARE),
+                '-' => (0, Score::PINS(0)),
+                'F' | 'f' => (0, Score::FOUL),
+                '1'..='9' => {
+                    let pins = symbol as usize - '0' as usize;
+                    (pins, Score::PINS(pins))
+                },
+                _ => return Err(ParseError::InvalidSymbol { frame, shot, symbol })
+            };
+
+            split = false;
+            current.push(pins);
+            current_scores.push(score);
+            standing -= pins.min(standing);
+            fresh = standing == 0;
+
+            if fresh {
+                standing = 10;
+            }
+
+            let complete = if frame < 9 {
+                pins == 10 && shot == 0 || current.len() == 2
+            } else {
+                current.len() == 3 || (current.len() == 2 && current[0] + current[1] < 10)
+            };
+
+            if complete {
+                frames.push(current);
+                scores.push(current_scores);
+                current = vec![];
+                current_scores = vec![];
+                standing = 10;
+                fresh = true;
+            }
+        }
+
+        // An `S` must be followed by the shot it marks
+        if split {
+            return Err(ParseError::InvalidSymbol { frame: frames.len(), shot: current.len(), symbol: 'S' });
+        }
+
+        if !current.is_empty() {
+            frames.push(current);
+            scores.push(current_scores);
+        }
+
+        // Check that the shots make a valid game before keeping the original symbols
+        try_parse_score(frames)?;
+
+        Ok(Game::new(scores.into_iter().map(|shots| Frame {
+            first: shots[0],
+            second: shots.get(1).copied().unwrap_or(Score::EMPTY),
+            bonus: shots.get(2).copied()
+        }).collect()))
+    }
+
+    /// Format the [Game] as scoresheet notation, such as `X 7/ 9- X X 81 -/ X X XX7`.
+    ///
+    /// Frames are separated by spaces. The result can be parsed again with [Game::from_notation].
+    /// Splits are circled, like `⑧1`, when the [Frame] marked or recorded them.
+    pub fn to_notation(&self) -> String {
+        self.iter().map(|frame| frame.marks().concat()).collect::<Vec<_>>().join(" ")
+    }
+}
+
+impl Frame {
+    /// Get the scoresheet mark of each shot of the [Frame], such as `["9", "/"]`.
+    ///
+    /// A shot at a full rack is circled, like `⑧`, if it is a [Score]::SPLIT or recorded pins that left a split.
+    pub fn marks(&self) -> Vec<String> {
+        let mut marks = vec![];
+        let mut standing = 10;
+        let mut fresh = true;
+
+        let scores = [self.first, self.second, self.bonus.unwrap_or(Score::EMPTY)];
+
+        for (i, pins) in self.shots().into_iter().enumerate() {
+            if scores[i] == Score::FOUL {
+                marks.push(Score::FOUL.to_string());
+                fresh = false;
+                continue;
+            }
+
+            let split = match scores[i] {
+                Score::SPLIT(_) => true,
+                Score::PINSET(down) => PinSet::all().difference(down).is_split(),
+                _ => false
+            };
+
+            // Circle a shot at a full rack that left a split
+            if fresh
+                && split
+                && let Some(circled) = char::from_u32('①' as u32 + pins as u32 - 1) {
+                marks.push(circled.to_string());
+                standing -= pins;
+                fresh = false;
+                continue;
+            }
+
+            let score = if pins == standing && fresh {
+                Score::STRIKE
+            } else if pins == standing {
+                Score::SPARE
+            } else {
+                Score::PINS(pins)
+            };
+
+            marks.push(score.to_string());
+            standing -= pins.min(standing);
+            fresh = standing == 0;
+
+            if fresh {
+                standing = 10;
+            }
+        }
+
+        marks
+    }
+}
+
+/// Get the pins and [Score] of a split that knocked down `pins` pins, if it can leave a split.
+fn split_score(pins: usize) -> Option<(usize, Score)> {
+    split::SPLIT_COUNTS.contains(&pins).then_some((pins, Score::SPLIT(pins)))
+}
+
+impl FromStr for Game {
+    type Err = ParseError;
+
+    fn from_str(notation: &str) -> Result<Self, Self::Err> {
+        Self::from_notation(notation)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Frame, Game, ParseError, PinSet, Score};
+
+    #[test]
+    fn from_notation_245() {
+        let game = Game::from_notation("7 1 | 9 / | X | 8 / | X | X | X | X | X | X X 7");
+        assert_eq!(game.map(|g| g.score()), Ok(245));
+    }
+
+    #[test]
+    fn from_notation_300() {
+        let game: Game = "XXXXXXXXXXXX".parse().unwrap();
+        assert!(game.is_perfect());
+    }
+
+    #[test]
+    fn from_notation_splits_and_fouls() {
+        let game = Game::from_notation("S7/ ⑧1 F9 -/").unwrap();
+
+        assert_eq!(game.frame(0), Some(&Frame::new(Score::SPLIT(7), Score::SPARE)));
+        assert!(game.frame(1).unwrap().is_split());
+        assert_eq!(game.frame(1).unwrap().named_leave(), None);
+        assert_eq!(game.to_notation(), "⑦/ ⑧1 F9 -/");
+        assert_eq!(game.score(), 46);
+    }
+
+    #[test]
+    fn split_round_trip() {
+        for notation in ["⑧1", "②/", "X X X X X X X X X X⑥-"] {
+            assert_eq!(Game::from_notation(notation).unwrap().to_notation(), notation);
+        }
+    }
+
+    #[test]
+    fn invalid_splits() {
+        assert_eq!(Game::from_notation("S").unwrap_err(), ParseError::InvalidSymbol { frame: 0, shot: 0, symbol: 'S' });
+        assert_eq!(Game::from_notation("X S").unwrap_err(), ParseError::InvalidSymbol { frame: 1, shot: 0, symbol: 'S' });
+        assert_eq!(Game::from_notation("SX").unwrap_err(), ParseError::InvalidSymbol { frame: 0, shot: 0, symbol: 'X' });
+        assert_eq!(Game::from_notation("SS8").unwrap_err(), ParseError::InvalidSymbol { frame: 0, shot: 0, symbol: 'S' });
+        assert_eq!(Game::from_notation("S9").unwrap_err(), ParseError::InvalidSymbol { frame: 0, shot: 0, symbol: '9' });
+        assert_eq!(Game::from_notation("①").unwrap_err(), ParseError::InvalidSymbol { frame: 0, shot: 0, symbol: '①' });
+    }
+
+    #[test]
+    fn foul_then_spare() {
+        let game = Game::from_notation("F/ 7-").unwrap();
+
+        assert_eq!(game.frame(0), Some(&Frame::new(Score::FOUL, Score::SPARE)));
+        assert_eq!(game.to_notation(), "F/ 7-");
+        assert_eq!(game.score(), 24);
+        assert!(Game::from_notation("FX").is_err());
+    }
+
+    #[test]
+    fn from_notation_invalid_symbol() {
+        let game = Game::from_notation("X /5");
+        assert_eq!(game.unwrap_err(), ParseError::InvalidSymbol { frame: 1, shot: 0, symbol: '/' });
+    }
+
+    #[test]
+    fn from_notation_overflow() {
+        let game = Game::from_notation("X 78");
+        assert_eq!(game.unwrap_err(), ParseError::FrameOverflow { frame: 1, shot: 1, pins: 8 });
+    }
+
+    #[test]
+    fn notation_round_trip() {
+        let notation = "X 7/ 9- X X 81 -/ X X XX7";
+        let game = Game::from_notation(notation).unwrap();
+
+        assert_eq!(game.to_notation(), notation);
+        assert_eq!(game.score(), 211);
+    }
+
+    #[test]
+    fn to_notation_splits() {
+        let seven_ten = PinSet::from_pins(&[1, 2, 3, 4, 5, 6, 8, 9]);
+        let game = Game::new(vec![Frame::new(Score::PINSET(seven_ten), Score::PINS(1)), Frame::new(Score::PINSET(seven_ten), Score::SPARE)]);
+
+        assert_eq!(game.to_notation(), "⑧1 ⑧/");
+        assert_eq!(Game::from_notation(&game.to_notation()).map(|g| g.to_notation()), Ok(game.to_notation()));
+    }
+
+    #[test]
+    fn to_notation_from_pins() {
+        let game = Game::new(vec![Frame::from([8usize, 2]), Frame::from([0usize, 0]), Frame::from([10usize, 10, 10])]);
+        assert_eq!(game.to_notation(), "8/ -- XXX");
+    }
+}
\ No newline at end of file
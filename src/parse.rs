@@ -1,6 +1,272 @@
-use crate::{Frame, Game, Score};
+use std::{error::Error, fmt};
+
+use crate::{split, Frame, Game, PinSet, Score};
+
+/// An error returned when frames, shots or a scoresheet notation can't be parsed into a valid [Game].
+///
+/// Frame and shot indices are zero-based, matching [Game::frame].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ParseError {
+    /// The frame count of the [Game] is 0, or more than [Game::MAX_FRAME_COUNT].
+    InvalidFrameCount {
+        /// The frame count of the [Game].
+        frame_count: usize
+    },
+    /// The [Game] has more frames than it allows.
+    TooManyFrames {
+        /// The index of the first extra frame.
+        frame: usize
+    },
+    /// A [Frame] has no shots.
+    EmptyFrame {
+        /// The index of the frame.
+        frame: usize
+    },
+    /// A [Frame] before the last one is missing a shot.
+    IncompleteFrame {
+        /// The index of the frame.
+        frame: usize,
+        /// The index of the missing shot.
+        shot: usize
+    },
+    /// A [Frame] has more shots than it is allowed.
+    TooManyShots {
+        /// The index of the frame.
+        frame: usize,
+        /// The index of the first extra shot.
+        shot: usize
+    },
+    /// A shot knocked down more than ten pins.
+    TooManyPins {
+        /// The index of the frame.
+        frame: usize,
+        /// The index of the shot.
+        shot: usize,
+        /// The number of pins the shot knocked down.
+        pins: usize
+    },
+    /// A shot knocked down more pins than were left standing.
+    FrameOverflow {
+        /// The index of the frame.
+        frame: usize,
+        /// The index of the shot.
+        shot: usize,
+        /// The number of pins the shot knocked down.
+        pins: usize
+    },
+    /// A [Score]::PINSET shot knocked down a pin that had already fallen.
+    PinDown {
+        /// The index of the frame.
+        frame: usize,
+        /// The index of the shot.
+        shot: usize,
+        /// The number of the pin.
+        pin: usize
+    },
+    /// A bonus shot was recorded in a [Frame] that didn't earn one.
+    UnexpectedBonus {
+        /// The index of the frame.
+        frame: usize,
+        /// The index of the bonus shot.
+        shot: usize
+    },
+    /// A bonus shot was expected in the final [Frame], but wasn't recorded.
+    MissingBonus {
+        /// The index of the frame.
+        frame: usize,
+        /// The index of the missing shot.
+        shot: usize
+    },
+    /// A bonus shot was said to be recorded, but the final [Frame] didn't earn one.
+    InvalidBonusFlag {
+        /// The index of the frame.
+        frame: usize
+    },
+    /// A symbol in a scoresheet notation wasn't recognized, or can't be used for the shot.
+    InvalidSymbol {
+        /// The index of the frame.
+        frame: usize,
+        /// The index of the shot.
+        shot: usize,
+        /// The symbol that couldn't be parsed.
+        symbol: char
+    },
+    /// A shot can't happen where it was recorded, such as a spare on the first ball or a shot after an empty one.
+    InvalidShot {
+        /// The index of the frame.
+        frame: usize,
+        /// The index of the shot.
+        shot: usize
+    }
+}
+
+impl ParseError {
+    /// The index of the [Frame] that caused the error.
+    pub fn frame(&self) -> usize {
+        match *self {
+            ParseError::InvalidFrameCount { .. } => 0,
+            ParseError::TooManyFrames { frame }
+            | ParseError::EmptyFrame { frame }
+            | ParseError::IncompleteFrame { frame, .. }
+            | ParseError::TooManyShots { frame, .. }
+            | ParseError::TooManyPins { frame, .. }
+            | ParseError::FrameOverflow { frame, .. }
+            | ParseError::PinDown { frame, .. }
+            | ParseError::UnexpectedBonus { frame, .. }
+            | ParseError::MissingBonus { frame, .. }
+            | ParseError::InvalidBonusFlag { frame }
+            | ParseError::InvalidSymbol { frame, .. }
+            | ParseError::InvalidShot { frame, .. } => frame
+        }
+    }
+
+    /// The index of the shot that caused the error.
+    pub fn shot(&self) -> usize {
+        match *self {
+            ParseError::InvalidFrameCount { .. }
+            | ParseError::TooManyFrames { .. }
+            | ParseError::EmptyFrame { .. }
+            | ParseError::InvalidBonusFlag { .. } => 0,
+            ParseError::IncompleteFrame { shot, .. }
+            | ParseError::TooManyShots { shot, .. }
+            | ParseError::TooManyPins { shot, .. }
+            | ParseError::FrameOverflow { shot, .. }
+            | ParseError::PinDown { shot, .. }
+            | ParseError::UnexpectedBonus { shot, .. }
+            | ParseError::MissingBonus { shot, .. }
+            | ParseError::InvalidSymbol { shot, .. }
+            | ParseError::InvalidShot { shot, .. } => shot
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let frame = self.frame() + 1;
+        let shot = self.shot() + 1;
+
+        match self {
+            ParseError::InvalidFrameCount { frame_count } => write!(formatter, "a game must have between 1 and {} frames, not {}", Game::MAX_FRAME_COUNT, frame_count),
+            ParseError::TooManyFrames { frame: limit } => write!(formatter, "frame {}: a game can't have more than {} frames", frame, limit),
+            ParseError::EmptyFrame { .. } => write!(formatter, "frame {}: frame has no shots", frame),
+            ParseError::IncompleteFrame { .. } => write!(formatter, "frame {}, shot {}: frame isn't finished", frame, shot),
+            ParseError::TooManyShots { .. } => write!(formatter, "frame {}, shot {}: frame has too many shots", frame, shot),
+            ParseError::TooManyPins { pins, .. } => write!(formatter, "frame {}, shot {}: {} pins is more than 10", frame, shot, pins),
+            ParseError::FrameOverflow { pins, .. } => write!(formatter, "frame {}, shot {}: {} pins is more than were standing", frame, shot, pins),
+            ParseError::PinDown { pin, .. } => write!(formatter, "frame {}, shot {}: pin {} was already knocked down", frame, shot, pin),
+            ParseError::UnexpectedBonus { .. } => write!(formatter, "frame {}, shot {}: bonus shot wasn't earned", frame, shot),
+            ParseError::MissingBonus { .. } => write!(formatter, "frame {}, shot {}: bonus shot is missing", frame, shot),
+            ParseError::InvalidBonusFlag { .. } => write!(formatter, "frame {}: frame has a bonus shot, but didn't earn one", frame),
+            ParseError::InvalidSymbol { symbol, .. } => write!(formatter, "frame {}, shot {}: unexpected symbol `{}`", frame, shot, symbol),
+            ParseError::InvalidShot { .. } => write!(formatter, "frame {}, shot {}: shot isn't possible here", frame, shot)
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+impl Game {
+    /// Check that the [Game] could have been bowled.
+    ///
+    /// Unlike [try_parse_score], any frame count up to [Game::MAX_FRAME_COUNT] is allowed, and the final frame may be unfinished.
+    /// Frames are checked against classic 10-pin rules, so games with three balls per frame, like candlepin, aren't valid.
+    /// A [Game] is invalid if:
+    /// - It has a frame count of 0 or more than [Game::MAX_FRAME_COUNT], or more frames than its frame count.
+    /// - A [Frame] before the last one has no shots, or a shot follows an empty one.
+    /// - A [Score]::PINS shot knocks down more than ten pins, or more pins than were left standing.
+    /// - A [Score]::PINSET shot knocks down a pin that had already fallen.
+    /// - A spare is recorded at a full rack, or a strike at a rack that isn't full.
+    /// - A [Score]::SPLIT is recorded at a rack that isn't full, or with a count that can't leave a split.
+    /// - A shot follows a strike before the final [Frame].
+    /// - A bonus shot is recorded in a [Frame] that isn't the final one, or in a final [Frame] without a strike or spare.
+    pub fn validate(&self) -> Result<(), ParseError> {
+        if self.frame_count == 0 || self.frame_count > Game::MAX_FRAME_COUNT {
+            return Err(ParseError::InvalidFrameCount { frame_count: self.frame_count });
+        }
+
+        if self.iter().count() > self.frame_count {
+            return Err(ParseError::TooManyFrames { frame: self.frame_count });
+        }
+
+        let last = self.iter().count().saturating_sub(1);
+
+        for (i, frame) in self.iter().enumerate() {
+            if i < last && frame.first == Score::EMPTY {
+                return Err(ParseError::EmptyFrame { frame: i });
+            }
+
+            let final_frame = i == self.frame_count - 1;
+            let mut ended = false;
+            let mut fresh = true;
+            let mut standing = 10;
+            // The pins standing, if every shot at the current rack recorded which pins were knocked down
+            let mut standing_pins = Some(PinSet::all());
+
+            for (shot, score) in [Some(frame.first), Some(frame.second), frame.bonus].into_iter().flatten().enumerate() {
+                // Checked first, since too many pins otherwise counts as empty
+                if let Score::PINS(pins) = score && pins > 10 {
+                    return Err(ParseError::TooManyPins { frame: i, shot, pins });
+                }
+
+                if score.is_empty() {
+                    ended = true;
+                    continue;
+                }
+
+                let invalid_split = matches!(score, Score::SPLIT(pins) if !fresh || !split::SPLIT_COUNTS.contains(&pins));
+
+                if ended || invalid_split || (score == Score::SPARE && fresh) || (score == Score::STRIKE && !fresh) {
+                    return Err(ParseError::InvalidShot { frame: i, shot });
+                }
+
+                // Only the final frame keeps going once the pins are cleared
+                if shot > 0 && fresh && !final_frame {
+                    return Err(ParseError::TooManyShots { frame: i, shot });
+                }
+
+                // Only a strike or spare earns the final frame's bonus shot
+                if shot == 2 && !fresh {
+                    return Err(ParseError::UnexpectedBonus { frame: i, shot });
+                }
+
+                if let Score::PINSET(pins) = score
+                    && let Some(standing_pins) = standing_pins
+                    && let Some(pin) = pins.difference(standing_pins).iter().next() {
+                    return Err(ParseError::PinDown { frame: i, shot, pin });
+                }
+
+                let pins = match score {
+                    Score::SPARE => standing,
+                    score => score.value()
+                };
+
+                if pins > standing {
+                    return Err(ParseError::FrameOverflow { frame: i, shot, pins });
+                }
+
+                standing -= pins;
+                standing_pins = match score {
+                    Score::PINSET(pins) => standing_pins.map(|standing| standing.difference(pins)),
+                    // Pins knocked down by a foul are reset
+                    Score::FOUL => standing_pins,
+                    _ => None
+                };
+                fresh = standing == 0;
+
+                if fresh {
+                    standing = 10;
+                    standing_pins = Some(PinSet::all());
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
 
 /// Parse a `Vec<Vec<usize>>` of frames into a [Game] struct.
+///
+/// The shots aren't checked, so use [try_parse_score] to reject a shot of more than ten pins or a frame that overflows.
 pub fn parse_score(frames: Vec<Vec<usize>>) -> Game {
     let mut parsed_frames = vec![];
 
@@ -11,9 +277,9 @@ pub fn parse_score(frames: Vec<Vec<usize>>) -> Game {
 
         // No values should count as `Score::EMPTY`
         parsed_frames.push(Frame {
-            first: first.and_then(|f| Some(Score::PINS(*f))).unwrap_or(Score::EMPTY),
-            second: second.and_then(|s| Some(Score::PINS(*s))).unwrap_or(Score::EMPTY),
-            bonus: bonus.and_then(|b| Some(Score::PINS(*b)))
+            first: first.map(|f| Score::PINS(*f)).unwrap_or(Score::EMPTY),
+            second: second.map(|s| Score::PINS(*s)).unwrap_or(Score::EMPTY),
+            bonus: bonus.map(|b| Score::PINS(*b))
         });
     }
 
@@ -21,12 +287,14 @@ pub fn parse_score(frames: Vec<Vec<usize>>) -> Game {
 }
 
 /// Parse a `Vec<usize>` into a [Game] struct.
+///
+/// The shots aren't checked, so use [try_parse_raw_score] to reject a shot of more than ten pins or a frame that overflows.
 pub fn parse_raw_score(shots: Vec<usize>, bonus: bool) -> Game {
     let mut parsed_frames = vec![];
     let mut skip = false;
 
     for i in 0..shots.len() {
-        let final_frame = i >= shots.len() - 3;
+        let final_frame = i + 3 >= shots.len();
 
         if skip {
             skip = false;
@@ -40,7 +308,7 @@ pub fn parse_raw_score(shots: Vec<usize>, bonus: bool) -> Game {
                 parsed_frames.push(Frame {
                     first: Score::STRIKE,
                     second: Score::PINS(*shots.get(i + 1).unwrap_or(&0)),
-                    bonus: shots.get(i + 2).and_then(|b| Some(Score::PINS(*b)))
+                    bonus: shots.get(i + 2).map(|b| Score::PINS(*b))
                 });
                 break;
             } else {
@@ -53,7 +321,7 @@ pub fn parse_raw_score(shots: Vec<usize>, bonus: bool) -> Game {
                 parsed_frames.push(Frame {
                     first: Score::PINS(shot),
                     second: Score::PINS(*shots.get(i + 1).unwrap_or(&0)),
-                    bonus: shots.get(i + 2).and_then(|b| Some(Score::PINS(*b)))
+                    bonus: shots.get(i + 2).map(|b| Score::PINS(*b))
                 });
                 break;
             } else {
@@ -67,9 +335,172 @@ pub fn parse_raw_score(shots: Vec<usize>, bonus: bool) -> Game {
     Game::with_frame_count(parsed_frames.len(), parsed_frames)
 }
 
+/// A shot for [try_parse_score_with_fouls] and [try_parse_raw_score_with_fouls].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Shot {
+    /// Shot knocked down `x` pins.
+    PINS(usize),
+    /// Shot was a foul, and is recorded as [Score]::FOUL, so no pins count.
+    FOUL
+}
+
+impl Shot {
+    /// The number of pins that count for the [Shot].
+    pub fn pins(&self) -> usize {
+        match self {
+            Shot::PINS(pins) => *pins,
+            Shot::FOUL => 0
+        }
+    }
+}
+
+impl From<usize> for Shot {
+    fn from(value: usize) -> Self {
+        Self::PINS(value)
+    }
+}
+
+/// Parse a `Vec<Vec<usize>>` of frames into a [Game] struct, checking that every frame is valid.
+///
+/// Unlike [parse_score()], the [Game] always has ten frames, so a partial game can be parsed.
+/// Only the last frame given may be unfinished. Use [try_parse_score_with_fouls] to record fouls.
+pub fn try_parse_score(frames: Vec<Vec<usize>>) -> Result<Game, ParseError> {
+    try_parse_score_with_fouls(frames.into_iter().map(|frame| frame.into_iter().map(Shot::from).collect()).collect())
+}
+
+/// Parse a `Vec<Vec<Shot>>` of frames into a [Game] struct, checking that every frame is valid.
+///
+/// Like [try_parse_score], but a shot can be a [Shot]::FOUL.
+pub fn try_parse_score_with_fouls(frames: Vec<Vec<Shot>>) -> Result<Game, ParseError> {
+    if frames.len() > 10 {
+        return Err(ParseError::TooManyFrames { frame: 10 });
+    }
+
+    let mut parsed_frames = vec![];
+
+    for (i, frame) in frames.iter().enumerate() {
+        parsed_frames.push(try_parse_frame(i, frame, i == frames.len() - 1)?);
+    }
+
+    Ok(Game::new(parsed_frames))
+}
+
+/// Parse a `Vec<usize>` into a [Game] struct, checking that every shot is valid.
+///
+/// Like [try_parse_score], the [Game] always has ten frames. Use [try_parse_raw_score_with_fouls] to record fouls.
+/// Every shot after the ninth frame belongs to the final frame.
+/// `bonus` is only checked once the final frame has been bowled, so a partial game can be parsed either way.
+pub fn try_parse_raw_score(shots: Vec<usize>, bonus: bool) -> Result<Game, ParseError> {
+    try_parse_raw_score_with_fouls(shots.into_iter().map(Shot::from).collect(), bonus)
+}
+
+/// Parse a `Vec<Shot>` into a [Game] struct, checking that every shot is valid.
+///
+/// Like [try_parse_raw_score], but a shot can be a [Shot]::FOUL.
+pub fn try_parse_raw_score_with_fouls(shots: Vec<Shot>, bonus: bool) -> Result<Game, ParseError> {
+    let mut frames = vec![];
+    let mut i = 0;
+
+    while i < shots.len() {
+        if frames.len() == 9 {
+            frames.push(shots[i..].to_vec());
+            break;
+        }
+
+        if shots[i] == Shot::PINS(10) || i + 1 == shots.len() {
+            frames.push(vec![shots[i]]);
+            i += 1;
+        } else {
+            frames.push(vec![shots[i], shots[i + 1]]);
+            i += 2;
+        }
+    }
+
+    let final_shots = frames.get(9).map(|f| f.len()).unwrap_or(0);
+    let game = try_parse_score_with_fouls(frames)?;
+
+    let Some(last) = game.frame(9) else {
+        return Ok(game);
+    };
+
+    if final_shots == 3 && !bonus {
+        return Err(ParseError::UnexpectedBonus { frame: 9, shot: 2 });
+    }
+
+    if final_shots < 3 && bonus {
+        if last.is_strike() || last.is_spare() {
+            return Err(ParseError::MissingBonus { frame: 9, shot: final_shots });
+        }
+
+        // An open final frame never has a bonus shot
+        if final_shots == 2 {
+            return Err(ParseError::InvalidBonusFlag { frame: 9 });
+        }
+    }
+
+    Ok(game)
+}
+
+/// Check the shots of a single frame and convert them into a [Frame].
+fn try_parse_frame(index: usize, shots: &[Shot], last: bool) -> Result<Frame, ParseError> {
+    let final_frame = index == 9;
+
+    if shots.is_empty() {
+        return Err(ParseError::EmptyFrame { frame: index });
+    }
+
+    let counts: Vec<usize> = shots.iter().map(Shot::pins).collect();
+
+    if let Some((shot, &pins)) = counts.iter().enumerate().find(|(_, pins)| **pins > 10) {
+        return Err(ParseError::TooManyPins { frame: index, shot, pins });
+    }
+
+    // A strike ends the frame, except for in the final frame
+    let max_shots = if final_frame { 3 } else if counts[0] == 10 { 1 } else { 2 };
+
+    if shots.len() > max_shots {
+        return Err(ParseError::TooManyShots { frame: index, shot: max_shots });
+    }
+
+    let mut standing = 10;
+
+    for (shot, &pins) in counts.iter().enumerate() {
+        // Only a strike or spare earns the final frame's bonus shot
+        if shot == 2 && counts[0] + counts[1] < 10 {
+            return Err(ParseError::UnexpectedBonus { frame: index, shot });
+        }
+
+        if pins > standing {
+            return Err(ParseError::FrameOverflow { frame: index, shot, pins });
+        }
+
+        standing -= pins;
+
+        // Pins are only reset in the final frame
+        if standing == 0 && final_frame {
+            standing = 10;
+        }
+    }
+
+    if !last && shots.len() == 1 && counts[0] < 10 {
+        return Err(ParseError::IncompleteFrame { frame: index, shot: 1 });
+    }
+
+    let score = |shot: Shot| match shot {
+        Shot::PINS(pins) => Score::PINS(pins),
+        Shot::FOUL => Score::FOUL
+    };
+
+    Ok(Frame {
+        first: score(shots[0]),
+        second: shots.get(1).map(|s| score(*s)).unwrap_or(Score::EMPTY),
+        bonus: shots.get(2).map(|b| score(*b))
+    })
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::{parse_score, parse_raw_score};
+    use crate::{parse_score, parse_raw_score, try_parse_raw_score, try_parse_raw_score_with_fouls, try_parse_score, try_parse_score_with_fouls, Frame, Game, ParseError, PinSet, Score, Shot};
 
     #[test]
     fn parse_game_300() {
@@ -109,4 +540,178 @@ mod tests {
         let game = parse_raw_score(vec![9, 1, 8, 2, 10, 10, 10, 10, 8, 2, 10, 10, 9], false);
         assert_eq!(game.score(), 223);
     }
+
+    #[test]
+    fn parse_raw_empty() {
+        let game = parse_raw_score(vec![], false);
+        assert_eq!(game.score(), 0);
+    }
+
+    #[test]
+    fn try_parse_game_245() {
+        let game = try_parse_score(vec![vec![7, 1], vec![9, 1], vec![10], vec![8, 2], vec![10], vec![10], vec![10], vec![10], vec![10], vec![10, 10, 7]]);
+        assert_eq!(game.map(|g| g.score()), Ok(245));
+    }
+
+    #[test]
+    fn try_parse_partial_game() {
+        let game = try_parse_score(vec![vec![10], vec![9]]).unwrap();
+
+        assert_eq!(game.frame_count, 10);
+        assert_eq!(game.max(), 280);
+    }
+
+    #[test]
+    fn try_parse_too_many_pins() {
+        let game = try_parse_score(vec![vec![7, 1], vec![11]]);
+        assert_eq!(game.unwrap_err(), ParseError::TooManyPins { frame: 1, shot: 0, pins: 11 });
+    }
+
+    #[test]
+    fn try_parse_frame_overflow() {
+        let game = try_parse_score(vec![vec![7, 8]]);
+        assert_eq!(game.unwrap_err(), ParseError::FrameOverflow { frame: 0, shot: 1, pins: 8 });
+    }
+
+    #[test]
+    fn try_parse_unexpected_bonus() {
+        let mut frames = vec![vec![10]; 9];
+        frames.push(vec![7, 1, 5]);
+
+        assert_eq!(try_parse_score(frames).unwrap_err(), ParseError::UnexpectedBonus { frame: 9, shot: 2 });
+    }
+
+    #[test]
+    fn try_parse_bonus_overflow() {
+        let mut frames = vec![vec![10]; 9];
+        frames.push(vec![10, 7, 5]);
+
+        assert_eq!(try_parse_score(frames).unwrap_err(), ParseError::FrameOverflow { frame: 9, shot: 2, pins: 5 });
+    }
+
+    #[test]
+    fn try_parse_incomplete_frame() {
+        let game = try_parse_score(vec![vec![7], vec![10]]);
+        assert_eq!(game.unwrap_err(), ParseError::IncompleteFrame { frame: 0, shot: 1 });
+    }
+
+    #[test]
+    fn try_parse_too_many_shots() {
+        let game = try_parse_score(vec![vec![10, 0]]);
+        assert_eq!(game.unwrap_err(), ParseError::TooManyShots { frame: 0, shot: 1 });
+    }
+
+    #[test]
+    fn try_parse_too_many_frames() {
+        let game = try_parse_score(vec![vec![10]; 11]);
+        assert_eq!(game.unwrap_err(), ParseError::TooManyFrames { frame: 10 });
+    }
+
+    #[test]
+    fn try_parse_raw_game_245() {
+        let game = try_parse_raw_score(vec![7, 1, 9, 1, 10, 8, 2, 10, 10, 10, 10, 10, 10, 10, 7], true);
+        assert_eq!(game.map(|g| g.score()), Ok(245));
+    }
+
+    #[test]
+    fn try_parse_raw_empty() {
+        let game = try_parse_raw_score(vec![], false);
+        assert_eq!(game.map(|g| g.score()), Ok(0));
+    }
+
+    #[test]
+    fn try_parse_raw_missing_bonus() {
+        let game = try_parse_raw_score(vec![10; 11], true);
+        assert_eq!(game.unwrap_err(), ParseError::MissingBonus { frame: 9, shot: 2 });
+    }
+
+    #[test]
+    fn try_parse_raw_partial_bonus() {
+        let game = try_parse_raw_score(vec![10, 7, 2], true);
+        assert_eq!(game.map(|g| g.score()), Ok(28));
+
+        let mut shots = vec![10; 9];
+        shots.push(7);
+        assert_eq!(try_parse_raw_score(shots, true).map(|g| g.frame_count), Ok(10));
+
+        let mut shots = vec![10; 9];
+        shots.extend([7, 3]);
+        assert_eq!(try_parse_raw_score(shots, true).unwrap_err(), ParseError::MissingBonus { frame: 9, shot: 2 });
+
+        let mut shots = vec![10; 10];
+        assert_eq!(try_parse_raw_score(shots.clone(), true).unwrap_err(), ParseError::MissingBonus { frame: 9, shot: 1 });
+
+        shots.pop();
+        shots.extend([7, 2]);
+        assert_eq!(try_parse_raw_score(shots.clone(), false).map(|g| g.score()), Ok(265));
+        assert_eq!(try_parse_raw_score(shots, true).unwrap_err(), ParseError::InvalidBonusFlag { frame: 9 });
+    }
+
+    #[test]
+    fn try_parse_raw_unexpected_bonus() {
+        let game = try_parse_raw_score(vec![10; 12], false);
+        assert_eq!(game.unwrap_err(), ParseError::UnexpectedBonus { frame: 9, shot: 2 });
+    }
+
+    #[test]
+    fn try_parse_fouls() {
+        let game = try_parse_score_with_fouls(vec![vec![Shot::FOUL, Shot::PINS(10)], vec![Shot::PINS(7), Shot::FOUL]]).unwrap();
+
+        assert_eq!(game.frame(0), Some(&Frame::new(Score::FOUL, Score::PINS(10))));
+        assert_eq!(game.frame(1), Some(&Frame::new(Score::PINS(7), Score::FOUL)));
+        assert_eq!(game.score(), 24);
+        assert_eq!(try_parse_score_with_fouls(vec![vec![Shot::FOUL, Shot::PINS(10), Shot::PINS(5)]]).unwrap_err(), ParseError::TooManyShots { frame: 0, shot: 2 });
+        assert_eq!(try_parse_score_with_fouls(vec![vec![Shot::FOUL, Shot::PINS(11)]]).unwrap_err(), ParseError::TooManyPins { frame: 0, shot: 1, pins: 11 });
+        assert_eq!(try_parse_score(vec![vec![usize::MAX]]).unwrap_err(), ParseError::TooManyPins { frame: 0, shot: 0, pins: usize::MAX });
+    }
+
+    #[test]
+    fn try_parse_raw_fouls() {
+        let game = try_parse_raw_score_with_fouls(vec![Shot::FOUL, Shot::PINS(10), Shot::PINS(7), Shot::PINS(2)], false).unwrap();
+
+        assert_eq!(game.frame(0), Some(&Frame::new(Score::FOUL, Score::PINS(10))));
+        assert_eq!(game.score(), 26);
+    }
+
+    #[test]
+    fn parse_error_display() {
+        let error = ParseError::FrameOverflow { frame: 0, shot: 1, pins: 8 };
+        assert_eq!(error.to_string(), "frame 1, shot 2: 8 pins is more than were standing");
+    }
+
+    #[test]
+    fn validate() {
+        assert_eq!(Game::new(vec![Frame::strike(); 9]).validate(), Ok(()));
+        assert_eq!(Game::new(vec![Frame::strike(), Frame::new(Score::PINS(7), Score::EMPTY)]).validate(), Ok(()));
+        assert_eq!(Game::with_frame_count(2, vec![Frame::strike(); 3]).validate(), Err(ParseError::TooManyFrames { frame: 2 }));
+        assert_eq!(Game::with_frame_count(0, vec![]).validate(), Err(ParseError::InvalidFrameCount { frame_count: 0 }));
+        assert_eq!(Game::with_frame_count(Game::MAX_FRAME_COUNT + 1, vec![]).validate(), Err(ParseError::InvalidFrameCount { frame_count: Game::MAX_FRAME_COUNT + 1 }));
+        assert_eq!(Game::new(vec![Frame::empty(), Frame::strike()]).validate(), Err(ParseError::EmptyFrame { frame: 0 }));
+        assert_eq!(Game::new(vec![Frame::new(Score::SPARE, Score::PINS(1))]).validate(), Err(ParseError::InvalidShot { frame: 0, shot: 0 }));
+        assert_eq!(Game::new(vec![Frame::new(Score::PINS(1), Score::STRIKE)]).validate(), Err(ParseError::InvalidShot { frame: 0, shot: 1 }));
+        assert_eq!(Game::new(vec![Frame::new(Score::PINS(12), Score::EMPTY)]).validate(), Err(ParseError::TooManyPins { frame: 0, shot: 0, pins: 12 }));
+        assert_eq!(Game::new(vec![Frame::with_bonus(Score::STRIKE, Score::EMPTY, Score::PINS(3))]).validate(), Err(ParseError::InvalidShot { frame: 0, shot: 2 }));
+    }
+
+    #[test]
+    fn validate_racks() {
+        let mut earned = vec![Frame::strike(); 9];
+        earned.push(Frame::with_bonus(Score::PINS(7), Score::SPARE, Score::STRIKE));
+
+        let mut unearned = vec![Frame::strike(); 9];
+        unearned.push(Frame::with_bonus(Score::PINS(7), Score::PINS(2), Score::PINS(7)));
+
+        let seven = PinSet::from_pins(&[1, 2, 3, 4, 5, 6, 8]);
+
+        assert_eq!(Game::new(earned).validate(), Ok(()));
+        assert_eq!(Game::new(unearned).validate(), Err(ParseError::UnexpectedBonus { frame: 9, shot: 2 }));
+        assert_eq!(Game::new(vec![Frame::new(Score::PINS(7), Score::PINS(8))]).validate(), Err(ParseError::FrameOverflow { frame: 0, shot: 1, pins: 8 }));
+        assert_eq!(Game::new(vec![Frame::new(Score::STRIKE, Score::PINS(3))]).validate(), Err(ParseError::TooManyShots { frame: 0, shot: 1 }));
+        assert_eq!(Game::new(vec![Frame::with_bonus(Score::PINS(3), Score::PINS(4), Score::PINS(2))]).validate(), Err(ParseError::UnexpectedBonus { frame: 0, shot: 2 }));
+        assert_eq!(Game::new(vec![Frame::new(Score::PINSET(seven), Score::PINSET(PinSet::from_pins(&[8, 9])))]).validate(), Err(ParseError::PinDown { frame: 0, shot: 1, pin: 8 }));
+        assert_eq!(Game::new(vec![Frame::new(Score::FOUL, Score::PINS(10))]).validate(), Ok(()));
+        assert_eq!(Game::new(vec![Frame::new(Score::SPLIT(8), Score::SPARE)]).validate(), Ok(()));
+        assert_eq!(Game::new(vec![Frame::new(Score::SPLIT(9), Score::PINS(1))]).validate(), Err(ParseError::InvalidShot { frame: 0, shot: 0 }));
+        assert_eq!(Game::new(vec![Frame::new(Score::PINS(2), Score::SPLIT(3))]).validate(), Err(ParseError::InvalidShot { frame: 0, shot: 1 }));
+    }
 }
\ No newline at end of file
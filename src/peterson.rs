@@ -0,0 +1,219 @@
+use std::cmp::Ordering;
+
+use crate::{Bowler, Game};
+
+/// Two [Bowler]s of a [PetersonScoring] round, and the [Game] each bowled.
+#[derive(Clone, Debug, Default, Hash)]
+pub struct PetersonMatch {
+    /// The indexes of the [Bowler]s.
+    pub bowlers: [usize; 2],
+    /// The [Game] bowled by each [Bowler].
+    pub games: [Game; 2]
+}
+
+impl PetersonMatch {
+    /// Create a new [PetersonMatch].
+    pub fn new(bowlers: [usize; 2], games: [Game; 2]) -> Self {
+        Self {
+            bowlers,
+            games
+        }
+    }
+}
+
+/// A row of the [PetersonScoring] standings.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct PetersonStanding {
+    /// The index of the [Bowler].
+    pub bowler: usize,
+    /// The Peterson points of the [Bowler].
+    pub points: f64,
+    /// The pins of the [Bowler].
+    pub pins: usize,
+    /// The number of [Game]s won.
+    pub wins: usize,
+    /// The number of [Game]s tied.
+    pub ties: usize,
+    /// The number of [Game]s lost.
+    pub losses: usize
+}
+
+/// Peterson point scoring for round-robin match play.
+///
+/// Each [Game] earns `win_points` for a win, `tie_points` for a tie,
+/// and a bonus point for every `pins_per_point` pins.
+/// [Game]s are scored with the [TenPinScoreProvider](crate::TenPinScoreProvider).
+#[derive(Clone, Debug)]
+pub struct PetersonScoring {
+    /// The [Bowler]s in match play.
+    pub bowlers: Vec<Bowler>,
+    /// The points for winning a [Game].
+    pub win_points: f64,
+    /// The points for tying a [Game].
+    pub tie_points: f64,
+    /// The pins needed for each bonus point.
+    pub pins_per_point: usize,
+    /// The [PetersonMatch]es of each round.
+    rounds: Vec<Vec<PetersonMatch>>
+}
+
+impl PetersonScoring {
+    /// Create a new [PetersonScoring], with 1 point per win, half a point per tie, and a bonus point per 50 pins.
+    pub fn new(bowlers: Vec<Bowler>) -> Self {
+        Self {
+            bowlers,
+            win_points: 1.0,
+            tie_points: 0.5,
+            pins_per_point: 50,
+            rounds: vec![]
+        }
+    }
+
+    /// Add a round of [PetersonMatch]es.
+    pub fn add_round(&mut self, matches: Vec<PetersonMatch>) {
+        self.rounds.push(matches);
+    }
+
+    /// Get a round of [PetersonMatch]es.
+    pub fn round(&self, index: usize) -> Option<&[PetersonMatch]> {
+        self.rounds.get(index).map(Vec::as_slice)
+    }
+
+    /// Count the rounds so far.
+    pub fn round_count(&self) -> usize {
+        self.rounds.len()
+    }
+
+    /// Calculate the Peterson points for a [Game] score against an opponent's score.
+    pub fn points(&self, score: usize, opponent: usize) -> f64 {
+        let result = match score.cmp(&opponent) {
+            Ordering::Greater => self.win_points,
+            Ordering::Equal => self.tie_points,
+            Ordering::Less => 0.0
+        };
+
+        // A pins_per_point of 0 means there is no pinfall bonus
+        let bonus = score.checked_div(self.pins_per_point).unwrap_or(0);
+
+        result + bonus as f64
+    }
+
+    /// Calculate the standings for a single round.
+    ///
+    /// Standings are sorted by points, then pins.
+    pub fn round_standings(&self, round: usize) -> Vec<PetersonStanding> {
+        self.standings_for(self.rounds.get(round).map(Vec::as_slice).unwrap_or_default().iter())
+    }
+
+    /// Calculate the cumulative standings through a round.
+    ///
+    /// Standings are sorted by points, then pins.
+    pub fn standings_through(&self, round: usize) -> Vec<PetersonStanding> {
+        self.standings_for(self.rounds.iter().take(round + 1).flatten())
+    }
+
+    /// Calculate the cumulative standings of every round.
+    ///
+    /// Standings are sorted by points, then pins.
+    pub fn standings(&self) -> Vec<PetersonStanding> {
+        self.standings_for(self.rounds.iter().flatten())
+    }
+
+    /// Calculate the standings for some [PetersonMatch]es.
+    fn standings_for<'a>(&self, matches: impl Iterator<Item = &'a PetersonMatch>) -> Vec<PetersonStanding> {
+        let mut standings: Vec<PetersonStanding> = (0..self.bowlers.len()).map(|bowler| PetersonStanding { bowler, ..PetersonStanding::default() }).collect();
+
+        for m in matches {
+            let scores = [m.games[0].score(), m.games[1].score()];
+
+            for (side, bowler) in m.bowlers.iter().enumerate() {
+                let Some(standing) = standings.get_mut(*bowler) else {
+                    continue;
+                };
+
+                let (score, opponent) = (scores[side], scores[1 - side]);
+
+                standing.points += self.points(score, opponent);
+                standing.pins += score;
+
+                match score.cmp(&opponent) {
+                    Ordering::Greater => standing.wins += 1,
+                    Ordering::Equal => standing.ties += 1,
+                    Ordering::Less => standing.losses += 1
+                }
+            }
+        }
+
+        standings.sort_by(|a, b| {
+            b.points.partial_cmp(&a.points).unwrap_or(Ordering::Equal)
+                .then(b.pins.cmp(&a.pins))
+                .then(a.bowler.cmp(&b.bowler))
+        });
+
+        standings
+    }
+}
+
+impl Default for PetersonScoring {
+    /// Create a [PetersonScoring] with no [Bowler]s, using the same points as [PetersonScoring::new].
+    fn default() -> Self {
+        Self::new(vec![])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Bowler, Frame, Game, PetersonMatch, PetersonScoring, Score};
+    use crate::fixtures::{clean_game, perfect_game};
+
+    fn open() -> Game {
+        Game::new(vec![Frame::new(Score::PINS(9), Score::PINS(0)); 10])
+    }
+
+    fn peterson() -> PetersonScoring {
+        let mut peterson = PetersonScoring::new(vec![Bowler::new("Homer", 200), Bowler::new("Marge", 190), Bowler::new("Lisa", 180)]);
+
+        peterson.add_round(vec![PetersonMatch::new([0, 1], [perfect_game(), clean_game()])]);
+        peterson.add_round(vec![PetersonMatch::new([1, 2], [open(), open()])]);
+        peterson
+    }
+
+    #[test]
+    fn points() {
+        let peterson = peterson();
+
+        assert_eq!(peterson.points(300, 190), 7.0);
+        assert_eq!(peterson.points(190, 300), 3.0);
+        assert_eq!(peterson.points(90, 90), 1.5);
+    }
+
+    #[test]
+    fn default_points() {
+        let peterson = PetersonScoring::default();
+
+        assert_eq!((peterson.win_points, peterson.tie_points, peterson.pins_per_point), (1.0, 0.5, 50));
+        assert_eq!(peterson.points(300, 190), 7.0);
+    }
+
+    #[test]
+    fn round_standings() {
+        let standings = peterson().round_standings(0);
+
+        assert_eq!(standings[0].bowler, 0);
+        assert_eq!(standings[0].points, 7.0);
+        assert_eq!(standings[1].points, 3.0);
+        assert_eq!(standings[1].losses, 1);
+        assert_eq!(standings[2].points, 0.0);
+    }
+
+    #[test]
+    fn cumulative_standings() {
+        let peterson = peterson();
+        let standings = peterson.standings();
+
+        assert_eq!(standings.iter().map(|standing| (standing.bowler, standing.points)).collect::<Vec<_>>(), vec![(0, 7.0), (1, 4.5), (2, 1.5)]);
+        assert_eq!(standings[1].pins, 280);
+        assert_eq!(standings[1].ties, 1);
+        assert_eq!(peterson.standings_through(0), peterson.round_standings(0));
+    }
+}
\ No newline at end of file
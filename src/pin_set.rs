@@ -0,0 +1,138 @@
+use std::fmt;
+
+/// A set of pins, numbered from 1.
+///
+/// Pins are stored as a bitmask, so only pins 1 to 10 can be in a [PinSet].
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct PinSet(u16);
+
+impl PinSet {
+    /// The highest pin number a [PinSet] can hold.
+    pub const MAX_PIN: usize = 10;
+
+    /// Create an empty [PinSet].
+    pub fn empty() -> Self {
+        Self::default()
+    }
+
+    /// Create a [PinSet] with all ten pins.
+    pub fn all() -> Self {
+        Self((1 << Self::MAX_PIN) - 1)
+    }
+
+    /// Create a [PinSet] from a bitmask, where bit `n - 1` is pin `n`.
+    ///
+    /// Bits past [PinSet::MAX_PIN] are ignored.
+    pub fn from_bits(bits: u16) -> Self {
+        Self(bits & Self::all().0)
+    }
+
+    /// Create a [PinSet] from a list of pin numbers.
+    ///
+    /// Pins outside of 1 to [PinSet::MAX_PIN] are ignored.
+    pub fn from_pins(pins: &[usize]) -> Self {
+        pins.iter().copied().collect()
+    }
+
+    /// Get the bitmask of the [PinSet], where bit `n - 1` is pin `n`.
+    pub fn bits(&self) -> u16 {
+        self.0
+    }
+
+    /// Check if the [PinSet] contains a pin.
+    pub fn contains(&self, pin: usize) -> bool {
+        (1..=Self::MAX_PIN).contains(&pin) && self.0 & (1 << (pin - 1)) != 0
+    }
+
+    /// Add a pin to the [PinSet].
+    pub fn insert(&mut self, pin: usize) {
+        if (1..=Self::MAX_PIN).contains(&pin) {
+            self.0 |= 1 << (pin - 1);
+        }
+    }
+
+    /// Remove a pin from the [PinSet].
+    pub fn remove(&mut self, pin: usize) {
+        if (1..=Self::MAX_PIN).contains(&pin) {
+            self.0 &= !(1 << (pin - 1));
+        }
+    }
+
+    /// Count the pins in the [PinSet].
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    /// Check if the [PinSet] has no pins.
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Combine the pins of two [PinSet]s.
+    pub fn union(&self, other: PinSet) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    /// Get the pins of the [PinSet] that aren't in another [PinSet].
+    pub fn difference(&self, other: PinSet) -> Self {
+        Self(self.0 & !other.0)
+    }
+
+    /// Returns an iterator for the pin numbers of the [PinSet], in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        (1..=Self::MAX_PIN).filter(|pin| self.contains(*pin))
+    }
+}
+
+impl FromIterator<usize> for PinSet {
+    fn from_iter<I: IntoIterator<Item = usize>>(iter: I) -> Self {
+        let mut set = Self::empty();
+
+        for pin in iter {
+            set.insert(pin);
+        }
+
+        set
+    }
+}
+
+impl fmt::Display for PinSet {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let pins: Vec<String> = self.iter().map(|pin| pin.to_string()).collect();
+        write!(formatter, "{}", pins.join("-"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::PinSet;
+
+    #[test]
+    fn from_pins() {
+        let set = PinSet::from_pins(&[7, 10, 11]);
+
+        assert_eq!(set.len(), 2);
+        assert_eq!(set.bits(), 0b10_0100_0000);
+        assert!(set.contains(10) && !set.contains(11));
+    }
+
+    #[test]
+    fn union_and_difference() {
+        let first = PinSet::from_pins(&[1, 2, 3]);
+        let second = PinSet::from_pins(&[3, 4]);
+
+        assert_eq!(first.union(second), PinSet::from_pins(&[1, 2, 3, 4]));
+        assert_eq!(first.difference(second), PinSet::from_pins(&[1, 2]));
+    }
+
+    #[test]
+    fn all() {
+        assert_eq!(PinSet::all().len(), 10);
+        assert_eq!(PinSet::all().difference(PinSet::from_pins(&[1, 2, 3, 4, 5, 6, 8, 9])), PinSet::from_pins(&[7, 10]));
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(PinSet::from_pins(&[10, 7]).to_string(), "7-10");
+    }
+}
\ No newline at end of file
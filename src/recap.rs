@@ -0,0 +1,257 @@
+use std::fmt::Write;
+
+use crate::{Bowler, GameStats, ScoreProvider, Series, SvgOptions, TenPinScoreProvider, svg::escape};
+
+/// The styles of a [Recap] page.
+const STYLE: &str = "body { font-family: sans-serif; margin: 2em; color: #222; }
+h1, h2 { font-weight: normal; }
+table { border-collapse: collapse; margin-bottom: 2em; }
+th, td { border: 1px solid #999; padding: 0.3em 0.8em; text-align: right; }
+th { background: #eee; }
+td:first-child, th:first-child { text-align: left; }
+.scoresheet { margin-bottom: 1em; }";
+
+/// A bowler's night in a [Recap].
+#[derive(Clone, Debug, Default, Hash)]
+pub struct RecapEntry {
+    /// The [Bowler].
+    pub bowler: Bowler,
+    /// The [Game](crate::Game)s of the night, with the [Bowler]'s handicap.
+    pub series: Series
+}
+
+impl RecapEntry {
+    /// Create a new [RecapEntry].
+    pub fn new(bowler: Bowler, series: Series) -> Self {
+        Self {
+            bowler,
+            series
+        }
+    }
+}
+
+/// A recap of a league night, as a self-contained HTML page.
+///
+/// The page has a table of every [Bowler]'s games, series and handicap totals, the high games and series,
+/// notable achievements such as clean games and converted splits, and a scoresheet for every [Game](crate::Game).
+#[derive(Clone, Debug, Default, Hash)]
+pub struct Recap {
+    /// The title of the [Recap].
+    pub title: String,
+    /// The [RecapEntry] of each [Bowler].
+    pub entries: Vec<RecapEntry>
+}
+
+impl Recap {
+    /// Create a new [Recap].
+    pub fn new(title: impl Into<String>, entries: Vec<RecapEntry>) -> Self {
+        Self {
+            title: title.into(),
+            entries
+        }
+    }
+
+    /// Add a [Bowler]'s night to the [Recap].
+    pub fn add_entry(&mut self, bowler: Bowler, series: Series) {
+        self.entries.push(RecapEntry::new(bowler, series));
+    }
+
+    /// Find the notable achievements of each [Bowler] using a [ScoreProvider], such as `"Homer: clean game (game 2)"`.
+    pub fn achievements_with_provider(&self, provider: impl ScoreProvider) -> Vec<String> {
+        let mut achievements = vec![];
+
+        for entry in &self.entries {
+            for (i, game) in entry.series.iter().enumerate() {
+                let stats = GameStats::new(game);
+                let mut notes = vec![];
+
+                // A perfect game is already every strike in a row, so its streak isn't repeated
+                if game.is_perfect_with_provider(provider) {
+                    notes.push(String::from("perfect game"));
+                } else {
+                    if stats.is_clean() {
+                        notes.push(String::from("clean game"));
+                    }
+
+                    match stats.longest_streak {
+                        3 => notes.push(String::from("turkey")),
+                        streak if streak > 3 => notes.push(format!("{} strikes in a row", streak)),
+                        _ => {}
+                    }
+                }
+
+                for frame in game.iter().filter(|frame| frame.is_split() && frame.is_spare()) {
+                    let leave = frame.named_leave().map(|leave| leave.to_string()).or_else(|| frame.leave().map(|pins| format!("{} split", pins)));
+                    // A split marked without its pins has no known leave
+                    notes.push(leave.map_or(String::from("converted a split"), |leave| format!("converted the {}", leave)));
+                }
+
+                for note in notes {
+                    achievements.push(format!("{}: {} (game {})", entry.bowler.name, note, i + 1));
+                }
+            }
+        }
+
+        achievements
+    }
+
+    /// Find the notable achievements of each [Bowler] using the [TenPinScoreProvider], such as `"Homer: clean game (game 2)"`.
+    pub fn achievements(&self) -> Vec<String> {
+        self.achievements_with_provider(TenPinScoreProvider)
+    }
+
+    /// Create the HTML page of the [Recap] using a [ScoreProvider].
+    pub fn to_html_with_provider(&self, provider: impl ScoreProvider) -> String {
+        let games = self.entries.iter().map(|entry| entry.series.len()).max().unwrap_or(0);
+        let mut html = String::new();
+
+        let _ = writeln!(html, "<!DOCTYPE html>");
+        let _ = writeln!(html, "<html>");
+        let _ = writeln!(html, "<head>");
+        let _ = writeln!(html, r#"<meta charset="utf-8">"#);
+        let _ = writeln!(html, "<title>{}</title>", escape(&self.title));
+        let _ = writeln!(html, "<style>\n{}\n</style>", STYLE);
+        let _ = writeln!(html, "</head>");
+        let _ = writeln!(html, "<body>");
+        let _ = writeln!(html, "<h1>{}</h1>", escape(&self.title));
+
+        // Every bowler's games and series
+        let _ = writeln!(html, "<table>");
+        let headers: Vec<String> = (1..=games).map(|game| format!("<th>Game {}</th>", game)).collect();
+        let _ = writeln!(html, "<tr><th>Bowler</th><th>Average</th>{}<th>Series</th><th>Handicap</th><th>Total</th></tr>", headers.concat());
+
+        for entry in &self.entries {
+            let scores = entry.series.scores_with_provider(provider);
+            let cells: Vec<String> = (0..games).map(|game| format!("<td>{}</td>", scores.get(game).map(|score| score.to_string()).unwrap_or_default())).collect();
+
+            let _ = writeln!(
+                html,
+                "<tr><td>{}</td><td>{}</td>{}<td>{}</td><td>{}</td><td>{}</td></tr>",
+                escape(&entry.bowler.name),
+                entry.bowler.average,
+                cells.concat(),
+                entry.series.total_with_provider(provider),
+                entry.series.handicap * entry.series.len(),
+                entry.series.handicap_total_with_provider(provider)
+            );
+        }
+
+        let _ = writeln!(html, "</table>");
+
+        // The highs of the night
+        let highs = [
+            ("High scratch game", self.high(|series| series.high_game_with_provider(provider))),
+            ("High handicap game", self.high(|series| series.high_game_with_provider(provider).map(|game| game + series.handicap))),
+            ("High scratch series", self.high(|series| Some(series.total_with_provider(provider)))),
+            ("High handicap series", self.high(|series| Some(series.handicap_total_with_provider(provider))))
+        ];
+
+        let _ = writeln!(html, "<h2>Highs</h2>");
+        let _ = writeln!(html, "<table>");
+
+        for (label, high) in highs {
+            if let Some((entry, score)) = high {
+                let _ = writeln!(html, "<tr><td>{}</td><td>{}</td><td>{}</td></tr>", label, escape(&entry.bowler.name), score);
+            }
+        }
+
+        let _ = writeln!(html, "</table>");
+
+        let achievements = self.achievements_with_provider(provider);
+
+        if !achievements.is_empty() {
+            let _ = writeln!(html, "<h2>Achievements</h2>");
+            let _ = writeln!(html, "<ul>");
+
+            for achievement in achievements {
+                let _ = writeln!(html, "<li>{}</li>", escape(&achievement));
+            }
+
+            let _ = writeln!(html, "</ul>");
+        }
+
+        let _ = writeln!(html, "<h2>Scoresheets</h2>");
+
+        for entry in &self.entries {
+            let options = SvgOptions {
+                handicap: (entry.series.handicap > 0).then_some(entry.series.handicap),
+                ..SvgOptions::new(entry.bowler.name.clone())
+            };
+
+            for game in &entry.series {
+                let _ = writeln!(html, r#"<div class="scoresheet">{}</div>"#, game.to_svg_with_provider(&options, provider));
+            }
+        }
+
+        html + "</body>\n</html>"
+    }
+
+    /// Create the HTML page of the [Recap] using the [TenPinScoreProvider].
+    pub fn to_html(&self) -> String {
+        self.to_html_with_provider(TenPinScoreProvider)
+    }
+
+    /// Find the [RecapEntry] with the highest value, keeping the earlier [RecapEntry] on a tie.
+    fn high(&self, value: impl Fn(&Series) -> Option<usize>) -> Option<(&RecapEntry, usize)> {
+        self.entries.iter()
+            .filter_map(|entry| value(&entry.series).map(|score| (entry, score)))
+            .fold(None, |high: Option<(&RecapEntry, usize)>, (entry, score)| match high {
+                Some((_, best)) if best >= score => high,
+                _ => Some((entry, score))
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Bowler, Frame, Game, PinSet, Recap, Score, Series};
+    use crate::fixtures::{clean_game, open_game, perfect_game};
+
+    fn recap() -> Recap {
+        let seven_ten = Score::PINSET(PinSet::all().difference(PinSet::from_pins(&[7, 10])));
+        let mut split_game = Game::from_notation("X | X | X | 9 / | 8 1 | 7 / | 9 - | X | 9 / | X X 8").unwrap();
+        split_game.set_frame(4, Frame::new(seven_ten, Score::SPARE));
+
+        let mut recap = Recap::new("Tuesday Night <Mixed>", vec![]);
+        recap.add_entry(Bowler::new("Homer", 180), Series::with_handicap(36, vec![split_game, clean_game()]));
+        recap.add_entry(Bowler::new("Marge", 200), Series::with_handicap(18, vec![perfect_game(), open_game()]));
+        recap
+    }
+
+    #[test]
+    fn achievements() {
+        assert_eq!(recap().achievements(), vec![
+            "Homer: turkey (game 1)",
+            "Homer: converted the 7-10 split (game 1)",
+            "Homer: clean game (game 2)",
+            "Marge: perfect game (game 1)"
+        ]);
+    }
+
+    #[test]
+    fn marked_split() {
+        let mut recap = Recap::new("Tuesday Night", vec![]);
+        recap.add_entry(Bowler::new("Homer", 180), Series::new(vec![Game::from_notation("S8/ 9-").unwrap()]));
+
+        assert_eq!(recap.achievements(), vec!["Homer: converted a split (game 1)"]);
+    }
+
+    #[test]
+    fn summary_table() {
+        let html = recap().to_html();
+
+        assert!(html.contains("<tr><th>Bowler</th><th>Average</th><th>Game 1</th><th>Game 2</th><th>Series</th><th>Handicap</th><th>Total</th></tr>"));
+        assert!(html.contains("<tr><td>Marge</td><td>200</td><td>300</td><td>90</td><td>390</td><td>36</td><td>426</td></tr>"));
+        assert!(html.contains("<tr><td>High scratch game</td><td>Marge</td><td>300</td></tr>"));
+    }
+
+    #[test]
+    fn self_contained() {
+        let html = recap().to_html();
+
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("<title>Tuesday Night &lt;Mixed&gt;</title>"));
+        assert_eq!(html.matches("<svg").count(), 4);
+        assert!(!html.contains("<link") && !html.contains("<script") && !html.contains("src="));
+    }
+}
\ No newline at end of file
@@ -1,13 +1,22 @@
 use std::{cmp::Ordering, fmt};
 
+use crate::PinSet;
+
 /// The [Score] of a shot.
-#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq)]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Score {
     /// Shot hasn't happened yet.
     #[default]
     EMPTY,
     /// Shot knocked down `x` pins.
     PINS(usize),
+    /// Shot knocked down the pins in the [PinSet].
+    PINSET(PinSet),
+    /// Shot knocked down `x` pins and left a split, without recording which pins were left.
+    SPLIT(usize),
+    /// Shot was a foul, so no pins count.
+    FOUL,
     /// Shot was a spare.
     SPARE,
     /// Shot was a strike.
@@ -18,23 +27,20 @@ impl Score {
     /// Caculate the value of the [Score].
     pub fn value(&self) -> usize {
         match self {
-            Score::EMPTY => 0,
-            Score::PINS(value) => match *value {
+            Score::EMPTY | Score::FOUL => 0,
+            Score::PINS(value) | Score::SPLIT(value) => match *value {
 				0..=10 => *value,
 				_ => 0
 			},
+            Score::PINSET(pins) => pins.len(),
             Score::SPARE | Score::STRIKE => 10
         }
     }
 
 	/// Check if the [Score] is considered "empty."
 	pub fn is_empty(&self) -> bool {
-		if let Score::PINS(value) = self {
-			if value >= &0 && value <= &10 {
-				return false;
-			} else {
-				return true;
-			}
+		if let Score::PINS(value) | Score::SPLIT(value) = self {
+			return *value > 10;
 		}
 		
 		*self == Score::EMPTY
@@ -47,20 +53,48 @@ impl Score {
         }
 
         if let Score::PINS(value) = self {
-            if value == &10 {
-                return true;
-            }
+            return *value == 10;
+        }
+
+        if let Score::PINSET(pins) = self {
+            return pins.len() == 10;
         }
 
         *self == Score::STRIKE
     }
+
+    /// Compare the pins knocked down by two [Score]s.
+    ///
+    /// [Score::EMPTY] is below every shot, [Score::STRIKE] is above every other shot,
+    /// and a [Score::SPARE] counts as 10 pins.
+    /// Unlike [Ord], different [Score]s can be equal, such as [Score::FOUL] and `Score::PINS(0)`.
+    pub fn cmp_pins(&self, other: &Self) -> Ordering {
+        let rank = |score: &Score| match score {
+            Score::EMPTY => (0, 0),
+            Score::STRIKE => (2, 0),
+            score => (1, score.pin_count())
+        };
+
+        rank(self).cmp(&rank(other))
+    }
+
+    /// Count the pins knocked down, without limiting the count to 10.
+    fn pin_count(&self) -> usize {
+        match self {
+            Score::PINS(value) | Score::SPLIT(value) => *value,
+            Score::PINSET(pins) => pins.len(),
+            score => score.value()
+        }
+    }
 }
 
 impl fmt::Display for Score {
     fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut value = match self {
             Score::EMPTY => String::from("<empty>"),
-            Score::PINS(v) => v.to_string(),
+            Score::PINS(v) | Score::SPLIT(v) => v.to_string(),
+            Score::PINSET(pins) => pins.len().to_string(),
+            Score::FOUL => String::from("F"),
             Score::SPARE => String::from("/"),
             Score::STRIKE => String::from("X")
         };
@@ -85,33 +119,28 @@ impl<V> From<V> for Score where V: Into<usize> {
     }
 }
 
-impl PartialOrd for Score {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(match self {
-            Score::EMPTY => match other {
-                Score::EMPTY => Ordering::Equal,
-                Score::PINS(_) => Ordering::Less,
-                Score::SPARE => Ordering::Less,
-                Score::STRIKE => Ordering::Less
-            },
-            Score::PINS(a) => match other {
-                Score::EMPTY => a.cmp(&0),
-                Score::PINS(b) => a.cmp(b),
-                Score::SPARE => Ordering::Less,
-                Score::STRIKE => Ordering::Less
-            },
-            Score::SPARE => match other {
-                Score::EMPTY => Ordering::Greater,
-                Score::PINS(b) => self.value().cmp(b),
-                Score::SPARE => Ordering::Equal,
-                Score::STRIKE => Ordering::Less
-            },
-            Score::STRIKE => match other {
-                Score::EMPTY => Ordering::Greater,
-                Score::PINS(_) => Ordering::Greater,
-                Score::SPARE => Ordering::Greater,
-                Score::STRIKE => Ordering::Equal
-            }
-        })
+#[cfg(test)]
+mod tests {
+    use std::{cmp::Ordering, collections::BTreeSet};
+
+    use crate::Score;
+
+    #[test]
+    fn total_order() {
+        let mut scores = vec![Score::PINS(0), Score::EMPTY, Score::FOUL, Score::PINS(0), Score::EMPTY];
+        scores.sort();
+
+        assert_eq!(scores, vec![Score::EMPTY, Score::EMPTY, Score::PINS(0), Score::PINS(0), Score::FOUL]);
+        assert_eq!(Score::EMPTY.cmp(&Score::PINS(0)), Score::PINS(0).cmp(&Score::EMPTY).reverse());
+        assert_eq!(BTreeSet::from([Score::FOUL, Score::PINS(0)]).len(), 2);
+    }
+
+    #[test]
+    fn cmp_pins() {
+        assert_eq!(Score::EMPTY.cmp_pins(&Score::PINS(0)), Ordering::Less);
+        assert_eq!(Score::FOUL.cmp_pins(&Score::PINS(0)), Ordering::Equal);
+        assert_eq!(Score::PINS(7).cmp_pins(&Score::PINS(3)), Ordering::Greater);
+        assert_eq!(Score::SPARE.cmp_pins(&Score::PINS(10)), Ordering::Equal);
+        assert_eq!(Score::STRIKE.cmp_pins(&Score::SPARE), Ordering::Greater);
     }
-}
\ No newline at end of file
+}
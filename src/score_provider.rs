@@ -1,10 +1,50 @@
 use crate::{Frame, Game, Score};
 
+/// The score of a single [Frame], as a scorer would write it on a scoresheet.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct FrameScore {
+    /// The running total of the [Game] up to this [Frame], or `None` while this or an earlier [Frame] is unresolved.
+    pub running: Option<usize>,
+    /// The points from the shots of the [Frame].
+    pub base: usize,
+    /// The bonus points earned from later shots so far.
+    pub bonus: usize,
+    /// Whether every shot the [Frame]'s score depends on has been bowled.
+    pub resolved: bool
+}
+
 /// Trait for all [ScoreProvider]s.
 pub trait ScoreProvider: Clone + Copy {
     /// Calculate the score for each [Frame] of a [Game].
     fn computed(&self, game: &Game) -> Vec<usize>;
 
+    /// Calculate the [FrameScore] for each [Frame] of a [Game].
+    ///
+    /// By default, the points of each [Frame] come from [ScoreProvider::computed], and a [Frame] is resolved
+    /// once it and the shots for a strike or spare bonus have been bowled, like in classic 10-pin bowling.
+    fn frame_scores(&self, game: &Game) -> Vec<FrameScore> {
+        let mut previous = 0;
+        let mut resolved_so_far = true;
+
+        self.computed(game).into_iter().enumerate().map(|(i, running)| {
+            let base = running.saturating_sub(previous);
+            previous = running;
+
+            let resolved = game.frame(i).is_some_and(|frame| {
+                let (complete, bonus_shots) = ten_pin_bonus(game, i, frame);
+                complete && later_shots(game, i, bonus_shots).len() == bonus_shots
+            });
+            resolved_so_far &= resolved;
+
+            FrameScore {
+                running: resolved_so_far.then_some(running),
+                base,
+                bonus: 0,
+                resolved
+            }
+        }).collect()
+    }
+
     /// Caculate the current score for a [Game].
     fn score(&self, game: &Game) -> usize {
         let computed = self.computed(game);
@@ -18,6 +58,11 @@ pub trait ScoreProvider: Clone + Copy {
 
     /// Calculate the maximum score achievable for a [Game].
     fn max(&self, game: &Game, provider: &impl ScoreProvider) -> usize;
+
+    /// Calculate the score of a perfect [Game] with the same number of frames.
+    fn perfect(&self, game: &Game) -> usize {
+        self.max(&Game::with_frame_count(game.frame_count, vec![]), self)
+    }
 }
 
 /// [ScoreProvider] for classic 10-pin bowling.
@@ -36,35 +81,29 @@ impl ScoreProvider for TenPinScoreProvider {
 
                 // Add bonus points
 				// TODO: fix bonus point system
-                if i < game.frame_count - 1 {
-                    if let Some(next) = game.frame(i + 1) {
-                        if frame.is_spare() {
-                            score += next.first.value();
-                        } else if frame.is_strike() {
-                            score += next.first.value();
-
-							// Broken here?
-                            if next.is_strike() {
-                                if i == game.frame_count - 2 {
-                                    // Fix for spares
-                                    score += next.second.value();
-                                } else {
-                                    if let Some(next2) = game.frame(i + 2) {
-                                        // Fix for spares
-                                        score += next2.first.value();
-                                    }
-                                }
-                            } else if next.is_spare() {
-                                score += 10 - next.first.value();
-                            } else {
-                                score += next.second.value().min(10 - next.first.value());
+                if i < game.frame_count - 1 && let Some(next) = game.frame(i + 1) {
+                    if frame.is_spare() {
+                        score += next.first.value();
+                    } else if frame.is_strike() {
+                        score += next.first.value();
+
+						// Broken here?
+                        if next.is_strike() {
+                            if i == game.frame_count - 2 {
+                                // Fix for spares
+                                score += next.second.value();
+                            } else if let Some(next2) = game.frame(i + 2) {
+                                // Fix for spares
+                                score += next2.first.value();
                             }
+                        } else if next.is_spare() {
+                            score += 10 - next.first.value();
+                        } else {
+                            score += next.second.value().min(10 - next.first.value());
                         }
                     }
                 }
 
-                println!("({}): {} -> {}", i, frame, score);
-
                 frames.push(score);
             }
         }
@@ -72,13 +111,41 @@ impl ScoreProvider for TenPinScoreProvider {
         frames
     }
 
+    fn frame_scores(&self, game: &Game) -> Vec<FrameScore> {
+        let mut scores = vec![];
+        let mut running = Some(0);
+
+        for i in 0..game.frame_count {
+            let Some(frame) = game.frame(i) else {
+                break;
+            };
+
+            let base = frame.shots().iter().sum();
+            let (complete, bonus_shots) = ten_pin_bonus(game, i, frame);
+            let later = later_shots(game, i, bonus_shots);
+            let bonus = later.iter().sum();
+            let resolved = complete && later.len() == bonus_shots;
+
+            running = running.filter(|_| resolved).map(|score| score + base + bonus);
+
+            scores.push(FrameScore {
+                running,
+                base,
+                bonus,
+                resolved
+            });
+        }
+
+        scores
+    }
+
     fn max(&self, game: &Game, provider: &impl ScoreProvider) -> usize {
         let mut frames = vec![];
 
         for i in 0..game.frame_count {
             if let Some(frame) = game.frame(i) {
                 if i == game.frame_count - 1 {
-                    let mut f = frame.clone();
+                    let mut f = *frame;
 
                     if f.first.is_empty() {
                         f.first = Score::STRIKE;
@@ -115,7 +182,7 @@ impl ScoreProvider for TenPinScoreProvider {
                     } else if frame.second.is_empty() {
                         frames.push(Frame::spare(frame.first.value()))
                     } else {
-                        frames.push(frame.clone());
+                        frames.push(*frame);
                     }
                 }
             } else {
@@ -129,4 +196,86 @@ impl ScoreProvider for TenPinScoreProvider {
 
         Game::with_frame_count(game.frame_count, frames).score_with_provider(*provider)
     }
-}
\ No newline at end of file
+}
+
+/// Check if every shot of the [Frame] at `index` has been bowled, and get how many later shots its 10-pin bonus needs.
+fn ten_pin_bonus(game: &Game, index: usize, frame: &Frame) -> (bool, usize) {
+    let shots = frame.shots();
+    let base: usize = shots.iter().sum();
+
+    // The final frame has no bonus points, since its bonus shots are part of the frame
+    if index == game.frame_count - 1 {
+        (shots.len() == 3 || (shots.len() == 2 && base < 10), 0)
+    } else if frame.is_strike() {
+        (true, 2)
+    } else {
+        (shots.len() == 2, if base >= 10 { 1 } else { 0 })
+    }
+}
+
+/// Get up to `count` shots bowled after the [Frame] at `index`.
+fn later_shots(game: &Game, index: usize, count: usize) -> Vec<usize> {
+    game.iter().take(game.frame_count).skip(index + 1).flat_map(|f| f.shots()).take(count).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Frame, FrameScore, Game, Score, ScoreProvider};
+
+    /// A [ScoreProvider] without bonus points, that keeps the default [ScoreProvider::frame_scores].
+    #[derive(Clone, Copy)]
+    struct PinsScoreProvider;
+
+    impl ScoreProvider for PinsScoreProvider {
+        fn computed(&self, game: &Game) -> Vec<usize> {
+            game.iter().take(game.frame_count).scan(0, |score, frame| {
+                *score += frame.value();
+                Some(*score)
+            }).collect()
+        }
+
+        fn max(&self, _: &Game, _: &impl ScoreProvider) -> usize {
+            unimplemented!()
+        }
+    }
+
+    #[test]
+    fn frame_scores_match_computed() {
+        let game = Game::new(vec![Frame::new(Score::PINS(7), Score::PINS(1)), Frame::spare(9), Frame::strike(), Frame::spare(8), Frame::strike(), Frame::strike(), Frame::strike(), Frame::strike(), Frame::strike(), Frame::with_bonus(Score::STRIKE, Score::STRIKE, Score::PINS(7))]);
+        let running: Vec<Option<usize>> = game.frame_scores().iter().map(|f| f.running).collect();
+
+        assert_eq!(running, game.computed().into_iter().map(Some).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn frame_scores_pending_strike() {
+        let game = Game::new(vec![Frame::spare(9), Frame::strike(), Frame::new(Score::PINS(7), Score::EMPTY)]);
+        let scores = game.frame_scores();
+
+        assert_eq!(scores[0], FrameScore { running: Some(20), base: 10, bonus: 10, resolved: true });
+        assert_eq!(scores[1], FrameScore { running: None, base: 10, bonus: 7, resolved: false });
+        assert_eq!(scores[2], FrameScore { running: None, base: 7, bonus: 0, resolved: false });
+    }
+
+    #[test]
+    fn frame_scores_final_frame() {
+        let mut game = Game::new(vec![Frame::strike(); 9]);
+        game.add_frame(Frame::with_bonus(Score::STRIKE, Score::STRIKE, Score::EMPTY));
+        let scores = game.frame_scores();
+
+        assert_eq!(scores[7].running, Some(240));
+        assert_eq!(scores[8].running, Some(270));
+        assert_eq!(scores[9], FrameScore { running: None, base: 20, bonus: 0, resolved: false });
+    }
+
+    #[test]
+    fn default_frame_scores() {
+        let game = Game::new(vec![Frame::spare(9), Frame::new(Score::PINS(7), Score::PINS(2)), Frame::strike(), Frame::new(Score::PINS(3), Score::EMPTY)]);
+        let scores = game.frame_scores_with_provider(PinsScoreProvider);
+
+        assert_eq!(scores[0], FrameScore { running: Some(10), base: 10, bonus: 0, resolved: true });
+        assert_eq!(scores[1], FrameScore { running: Some(19), base: 9, bonus: 0, resolved: true });
+        assert_eq!(scores[2], FrameScore { running: None, base: 10, bonus: 0, resolved: false });
+        assert_eq!(scores[3], FrameScore { running: None, base: 3, bonus: 0, resolved: false });
+    }
+}
@@ -0,0 +1,269 @@
+use crate::{Game, ScoreProvider, TenPinScoreProvider};
+
+/// The characters a [Scoresheet] is drawn with.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum BoxStyle {
+    /// Plain ASCII, using `+`, `-` and `|`.
+    #[default]
+    Ascii,
+    /// Unicode box-drawing characters.
+    Unicode
+}
+
+/// The characters for each part of the grid, in the order they are drawn.
+struct Glyphs {
+    horizontal: char,
+    vertical: char,
+    top: [char; 3],
+    /// The middle line, with a separate joint for boxes within a frame.
+    middle: [char; 4],
+    bottom: [char; 3]
+}
+
+impl BoxStyle {
+    fn glyphs(&self) -> Glyphs {
+        match self {
+            BoxStyle::Ascii => Glyphs {
+                horizontal: '-',
+                vertical: '|',
+                top: ['+', '+', '+'],
+                middle: ['+', '+', '+', '+'],
+                bottom: ['+', '+', '+']
+            },
+            BoxStyle::Unicode => Glyphs {
+                horizontal: '─',
+                vertical: '│',
+                top: ['┌', '┬', '┐'],
+                middle: ['├', '┴', '┼', '┤'],
+                bottom: ['└', '┴', '┘']
+            }
+        }
+    }
+}
+
+/// A frame, ready to be drawn.
+pub(crate) struct Cell {
+    /// The mark for each box, with blank boxes left empty.
+    pub(crate) marks: Vec<String>,
+    /// The pins of the first shot, if it left a split.
+    pub(crate) split: Option<usize>,
+    /// The running total, or `None` if the frame is unresolved.
+    pub(crate) total: Option<usize>
+}
+
+/// Get the [Cell] for each frame of a [Game], using a [ScoreProvider] for the totals.
+///
+/// The final frame, and any frame with a third ball, gets three boxes.
+pub(crate) fn cells(game: &Game, provider: impl ScoreProvider) -> Vec<Cell> {
+    let scores = game.frame_scores_with_provider(provider);
+
+    (0..game.frame_count).map(|i| {
+        let frame = game.frame(i);
+        let boxes = if i == game.frame_count - 1 || frame.is_some_and(|frame| frame.bonus.is_some()) { 3 } else { 2 };
+        let mut marks = frame.map(|frame| frame.marks()).unwrap_or_default();
+
+        // A strike goes in the last box, like on a paper scoresheet
+        if boxes == 2 && frame.is_some_and(|frame| frame.is_strike()) {
+            marks.insert(0, String::new());
+        }
+
+        marks.resize(boxes, String::new());
+
+        Cell {
+            marks,
+            split: frame.filter(|frame| frame.is_split()).and_then(|frame| frame.shots().first().copied()),
+            total: scores.get(i).and_then(|score| score.running)
+        }
+    }).collect()
+}
+
+/// Draws a [Game] as a scoresheet, with the marks of each shot in small boxes and the running total beneath.
+///
+/// The final frame, and any frame with a third ball, gets three boxes.
+/// Totals are left blank until a frame is resolved.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Scoresheet {
+    /// The characters the [Scoresheet] is drawn with.
+    pub style: BoxStyle,
+    /// The width of each shot's box.
+    pub ball_width: usize,
+    /// The most characters a line can have, if there is a limit.
+    ///
+    /// Boxes are narrowed to fit, and if they still don't, the frames are split across several grids.
+    pub width: Option<usize>
+}
+
+impl Scoresheet {
+    /// Create a new [Scoresheet].
+    pub fn new(style: BoxStyle) -> Self {
+        Self {
+            style,
+            ball_width: 3,
+            width: None
+        }
+    }
+
+    /// Create a new [Scoresheet] that fits within a width, such as the width of a terminal.
+    pub fn with_width(style: BoxStyle, width: usize) -> Self {
+        Self {
+            width: Some(width),
+            ..Self::new(style)
+        }
+    }
+
+    /// Draw a [Game] using a [ScoreProvider] for the totals.
+    pub fn render_with_provider(&self, game: &Game, provider: impl ScoreProvider) -> String {
+        let cells = cells(game, provider);
+        let longest = cells.iter().flat_map(|cell| &cell.marks).map(|mark| mark.chars().count()).max().unwrap_or(0).max(1);
+        let mut ball_width = self.ball_width.max(longest);
+
+        if let Some(width) = self.width {
+            while ball_width > longest && line_width(&cells, ball_width) > width {
+                ball_width -= 1;
+            }
+        }
+
+        // Split the frames into grids that fit
+        let mut grids: Vec<&[Cell]> = vec![];
+        let mut rest = &cells[..];
+
+        while !rest.is_empty() {
+            let mut count = rest.len();
+
+            if let Some(width) = self.width {
+                while count > 1 && line_width(&rest[..count], ball_width) > width {
+                    count -= 1;
+                }
+            }
+
+            grids.push(&rest[..count]);
+            rest = &rest[count..];
+        }
+
+        grids.into_iter().map(|grid| self.render_grid(grid, ball_width)).collect::<Vec<_>>().join("\n")
+    }
+
+    /// Draw a [Game] using the [TenPinScoreProvider] for the totals.
+    pub fn render(&self, game: &Game) -> String {
+        self.render_with_provider(game, TenPinScoreProvider)
+    }
+
+    /// Draw the five lines of a single grid.
+    fn render_grid(&self, cells: &[Cell], ball_width: usize) -> String {
+        let glyphs = self.style.glyphs();
+        let horizontal = glyphs.horizontal.to_string().repeat(ball_width);
+
+        let mut top = glyphs.top[0].to_string();
+        let mut marks = glyphs.vertical.to_string();
+        let mut middle = glyphs.middle[0].to_string();
+        let mut totals = glyphs.vertical.to_string();
+        let mut bottom = glyphs.bottom[0].to_string();
+
+        for (i, cell) in cells.iter().enumerate() {
+            let last_cell = i == cells.len() - 1;
+            let content = cell_width(cell, ball_width);
+
+            for (j, mark) in cell.marks.iter().enumerate() {
+                let last_box = j == cell.marks.len() - 1;
+
+                top += &horizontal;
+                top.push(if last_box && last_cell { glyphs.top[2] } else { glyphs.top[1] });
+
+                marks += &format!("{:^ball_width$}", mark);
+                marks.push(glyphs.vertical);
+
+                middle += &horizontal;
+                middle.push(if !last_box { glyphs.middle[1] } else if last_cell { glyphs.middle[3] } else { glyphs.middle[2] });
+            }
+
+            totals += &format!("{:^content$}", cell.total.map(|total| total.to_string()).unwrap_or_default());
+            totals.push(glyphs.vertical);
+
+            bottom += &glyphs.horizontal.to_string().repeat(content);
+            bottom.push(if last_cell { glyphs.bottom[2] } else { glyphs.bottom[1] });
+        }
+
+        [top, marks, middle, totals, bottom].join("\n")
+    }
+}
+
+impl Default for Scoresheet {
+    fn default() -> Self {
+        Self::new(BoxStyle::Ascii)
+    }
+}
+
+/// The width of the inside of a frame.
+fn cell_width(cell: &Cell, ball_width: usize) -> usize {
+    cell.marks.len() * (ball_width + 1) - 1
+}
+
+/// The width of a line of a grid.
+fn line_width(cells: &[Cell], ball_width: usize) -> usize {
+    1 + cells.iter().map(|cell| cell_width(cell, ball_width) + 1).sum::<usize>()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BoxStyle, Frame, Game, LiveGame, Scoresheet, Score, fixtures};
+
+    fn game() -> Game {
+        Game::with_frame_count(3, vec![Frame::strike(), Frame::spare(9), Frame::new(Score::PINS(8), Score::PINS(1))])
+    }
+
+    #[test]
+    fn ascii() {
+        assert_eq!(Scoresheet::new(BoxStyle::Ascii).render(&game()), [
+            "+---+---+---+---+---+---+---+",
+            "|   | X | 9 | / | 8 | 1 |   |",
+            "+---+---+---+---+---+---+---+",
+            "|  20   |  38   |    47     |",
+            "+-------+-------+-----------+"
+        ].join("\n"));
+    }
+
+    #[test]
+    fn unicode() {
+        assert_eq!(Scoresheet::new(BoxStyle::Unicode).render(&game()), [
+            "┌───┬───┬───┬───┬───┬───┬───┐",
+            "│   │ X │ 9 │ / │ 8 │ 1 │   │",
+            "├───┴───┼───┴───┼───┴───┴───┤",
+            "│  20   │  38   │    47     │",
+            "└───────┴───────┴───────────┘"
+        ].join("\n"));
+    }
+
+    #[test]
+    fn unresolved_frames() {
+        let mut live = LiveGame::with_frame_count(3);
+        live.roll(10).unwrap();
+        live.roll(7).unwrap();
+
+        assert_eq!(Scoresheet::new(BoxStyle::Ascii).render(live.game()).lines().nth(3), Some("|       |       |           |"));
+    }
+
+    #[test]
+    fn narrow_boxes() {
+        let sheet = Scoresheet::with_width(BoxStyle::Ascii, 60).render(&fixtures::game());
+
+        assert!(sheet.lines().all(|line| line.chars().count() == 43));
+        assert_eq!(sheet.lines().nth(1), Some("| |X|9|/|8|1| |X| |X|7|/|9|-| |X|9|/|X|X|8|"));
+    }
+
+    #[test]
+    fn wrapped_grids() {
+        let game = Game::new(vec![Frame::strike(); 9]);
+        let sheet = Scoresheet::with_width(BoxStyle::Unicode, 20).render(&game);
+
+        assert_eq!(sheet.lines().count(), 15);
+        assert!(sheet.lines().all(|line| line.chars().count() <= 20));
+        assert_eq!(sheet.lines().nth(8), Some("│150│180│210│   │"));
+        assert_eq!(sheet.lines().nth(13), Some("│   │     │"));
+    }
+
+    #[test]
+    fn game_display() {
+        assert_eq!(game().to_string(), Scoresheet::default().render(&game()));
+        assert_eq!(format!("{:#}", game()), Scoresheet::new(BoxStyle::Unicode).render(&game()));
+    }
+}
\ No newline at end of file
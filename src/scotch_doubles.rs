@@ -0,0 +1,173 @@
+use crate::{Bowler, FrameScore, Game, GameStats, LiveGame, PinSet, RollError};
+
+/// A single ball of a [ScotchDoublesGame].
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Delivery {
+    /// The index of the [Bowler] who threw the ball.
+    pub bowler: usize,
+    /// The index of the [Frame](crate::Frame) the ball belongs to.
+    pub frame: usize,
+    /// The number of pins the ball knocked down.
+    pub pins: usize
+}
+
+/// A scotch doubles [Game], where two [Bowler]s take turns throwing each ball.
+///
+/// The first [Bowler] throws the first ball, and the [Bowler]s alternate from there,
+/// so after a strike the other [Bowler] starts the next [Frame](crate::Frame).
+#[derive(Clone, Debug, Default, Hash)]
+pub struct ScotchDoublesGame {
+    /// The two [Bowler]s, in the order they bowl.
+    pub bowlers: [Bowler; 2],
+    /// The shared [LiveGame].
+    live: LiveGame
+}
+
+impl ScotchDoublesGame {
+    /// Create a new [ScotchDoublesGame].
+    pub fn new(bowlers: [Bowler; 2]) -> Self {
+        Self {
+            bowlers,
+            live: LiveGame::new()
+        }
+    }
+
+    /// Add a shot by the next [Bowler], knocking down a number of pins.
+    pub fn roll(&mut self, pins: usize) -> Result<(), RollError> {
+        self.live.roll(pins)
+    }
+
+    /// Add a shot by the next [Bowler], knocking down a [PinSet].
+    pub fn roll_pins(&mut self, pins: PinSet) -> Result<(), RollError> {
+        self.live.roll_pins(pins)
+    }
+
+    /// Add a fouled shot by the next [Bowler].
+    pub fn foul(&mut self) -> Result<(), RollError> {
+        self.live.foul()
+    }
+
+    /// Get every ball thrown so far, in order.
+    pub fn deliveries(&self) -> Vec<Delivery> {
+        let mut deliveries = vec![];
+
+        for (frame, shots) in self.live.game().iter().map(|frame| frame.shots()).enumerate() {
+            for pins in shots {
+                deliveries.push(Delivery {
+                    bowler: deliveries.len() % 2,
+                    frame,
+                    pins
+                });
+            }
+        }
+
+        deliveries
+    }
+
+    /// The index of the [Bowler] who throws the next ball, or `None` if the [ScotchDoublesGame] is complete.
+    pub fn next_bowler(&self) -> Option<usize> {
+        (!self.live.is_complete()).then(|| self.deliveries().len() % 2)
+    }
+
+    /// Calculate the [GameStats] for the balls thrown by a [Bowler].
+    ///
+    /// Only ball statistics are counted, as every [Frame](crate::Frame) is shared.
+    pub fn bowler_stats(&self, bowler: usize) -> GameStats {
+        let mut stats = GameStats {
+            games: 1,
+            ..GameStats::default()
+        };
+        let mut streak = 0;
+        let mut ball = 0;
+
+        for frame in self.live.game() {
+            let mut first = None;
+
+            for pins in frame.shots() {
+                if ball % 2 == bowler {
+                    stats.record_ball(pins, first, &mut streak);
+                }
+
+                first = if first.is_none() && pins < 10 { Some(pins) } else { None };
+                ball += 1;
+            }
+        }
+
+        stats
+    }
+
+    /// Check if the [ScotchDoublesGame] is complete.
+    pub fn is_complete(&self) -> bool {
+        self.live.is_complete()
+    }
+
+    /// Get the shared [Game].
+    pub fn game(&self) -> &Game {
+        self.live.game()
+    }
+
+    /// Calculate the [FrameScore] for each [Frame](crate::Frame) so far using the [TenPinScoreProvider](crate::TenPinScoreProvider).
+    pub fn frame_scores(&self) -> Vec<FrameScore> {
+        self.live.frame_scores()
+    }
+
+    /// Calculate the current team score using the [TenPinScoreProvider](crate::TenPinScoreProvider).
+    pub fn score(&self) -> usize {
+        self.live.score()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Bowler, Delivery, ScotchDoublesGame};
+
+    fn scotch() -> ScotchDoublesGame {
+        let mut scotch = ScotchDoublesGame::new([Bowler::new("Homer", 150), Bowler::new("Marge", 170)]);
+
+        for pins in [10, 9, 1, 8, 1, 10] {
+            scotch.roll(pins).unwrap();
+        }
+
+        scotch
+    }
+
+    #[test]
+    fn alternating_balls() {
+        let scotch = scotch();
+        let deliveries = scotch.deliveries();
+
+        assert_eq!(deliveries[1], Delivery { bowler: 1, frame: 1, pins: 9 });
+        assert_eq!(deliveries[3], Delivery { bowler: 1, frame: 2, pins: 8 });
+        assert_eq!(deliveries[5], Delivery { bowler: 1, frame: 3, pins: 10 });
+        assert_eq!(scotch.next_bowler(), Some(0));
+        assert_eq!(scotch.score(), 57);
+    }
+
+    #[test]
+    fn bowler_stats() {
+        let scotch = scotch();
+        let homer = scotch.bowler_stats(0);
+        let marge = scotch.bowler_stats(1);
+
+        assert_eq!(homer.strike_rate(), Some(1.0));
+        assert_eq!(homer.spare_conversion(), Some(0.5));
+        assert_eq!(homer.single_pin_conversion(), Some(1.0));
+        assert_eq!(marge.first_balls, 3);
+        assert_eq!(marge.strike_rate(), Some(1.0 / 3.0));
+        assert_eq!(marge.spare_conversion(), None);
+    }
+
+    #[test]
+    fn complete() {
+        let mut scotch = ScotchDoublesGame::default();
+
+        for _ in 0..12 {
+            scotch.roll(10).unwrap();
+        }
+
+        assert!(scotch.is_complete());
+        assert_eq!(scotch.next_bowler(), None);
+        assert_eq!(scotch.bowler_stats(0).strikes, 6);
+        assert_eq!(scotch.bowler_stats(1).longest_streak, 6);
+    }
+}
\ No newline at end of file
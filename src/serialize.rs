@@ -0,0 +1,123 @@
+//! [Serialize] and [Deserialize] implementations for the types that need more than a derive.
+//!
+//! A [PinSet] is stored as a list of pin numbers, such as `[7, 10]`.
+//! A [Game] is stored as its `frame_count` and `frames`, and is checked with [Game::validate] when it is loaded.
+
+use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer, ser::SerializeStruct};
+
+use crate::{Frame, Game, PinSet};
+
+impl Serialize for PinSet {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+impl<'de> Deserialize<'de> for PinSet {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let pins = Vec::<usize>::deserialize(deserializer)?;
+
+        if let Some(pin) = pins.iter().find(|pin| !(1..=PinSet::MAX_PIN).contains(*pin)) {
+            return Err(D::Error::custom(format!("pin {} isn't between 1 and {}", pin, PinSet::MAX_PIN)));
+        }
+
+        Ok(PinSet::from_pins(&pins))
+    }
+}
+
+impl Serialize for Game {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut game = serializer.serialize_struct("Game", 2)?;
+        game.serialize_field("frame_count", &self.frame_count)?;
+        game.serialize_field("frames", self.frames())?;
+        game.end()
+    }
+}
+
+/// The stored form of a [Game], before it is validated.
+#[derive(Deserialize)]
+#[serde(rename = "Game", deny_unknown_fields)]
+struct GameData {
+    frame_count: usize,
+    frames: Vec<Frame>
+}
+
+impl<'de> Deserialize<'de> for Game {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let data = GameData::deserialize(deserializer)?;
+        let game = Game::with_frame_count(data.frame_count, data.frames);
+
+        game.validate().map_err(D::Error::custom)?;
+        Ok(game)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Frame, Game, PinSet, Score, fixtures};
+
+    #[test]
+    fn schema() {
+        let game = Game::new(vec![Frame::strike(), Frame::new(Score::PINSET(PinSet::from_pins(&[1, 2, 3, 4, 5, 6, 8, 9])), Score::FOUL)]);
+        let json = serde_json::to_string(&game).unwrap();
+
+        assert_eq!(json, r#"{"frame_count":10,"frames":[{"first":"STRIKE","second":"EMPTY","bonus":null},{"first":{"PINSET":[1,2,3,4,5,6,8,9]},"second":"FOUL","bonus":null}]}"#);
+    }
+
+    #[test]
+    fn round_trip() {
+        let game = fixtures::game();
+        let loaded: Game = serde_json::from_str(&serde_json::to_string(&game).unwrap()).unwrap();
+
+        assert_eq!(loaded.frames(), game.frames());
+        assert_eq!(loaded.frame_count, 10);
+        assert_eq!(loaded.score(), 190);
+    }
+
+    #[test]
+    fn missing_bonus() {
+        let game: Game = serde_json::from_str(r#"{"frame_count":3,"frames":[{"first":{"PINS":7},"second":"SPARE"}]}"#).unwrap();
+        assert_eq!(game.frame(0), Some(&Frame::spare(7)));
+    }
+
+    #[test]
+    fn invalid_games() {
+        let too_many_frames = r#"{"frame_count":1,"frames":[{"first":"STRIKE","second":"EMPTY"},{"first":"STRIKE","second":"EMPTY"}]}"#;
+        let spare_first = r#"{"frame_count":10,"frames":[{"first":"SPARE","second":"EMPTY"}]}"#;
+        let bad_pin = r#"{"frame_count":10,"frames":[{"first":{"PINSET":[11]},"second":"EMPTY"}]}"#;
+        let missing_count = r#"{"frames":[]}"#;
+
+        for json in [too_many_frames, spare_first, bad_pin, missing_count] {
+            assert!(serde_json::from_str::<Game>(json).is_err(), "{} should be rejected", json);
+        }
+
+        let error = serde_json::from_str::<Game>(spare_first).unwrap_err();
+        assert_eq!(error.to_string(), "frame 1, shot 1: shot isn't possible here");
+    }
+
+    #[test]
+    fn impossible_racks() {
+        let overflow = r#"{"frame_count":10,"frames":[{"first":{"PINS":7},"second":{"PINS":8}}]}"#;
+        let after_strike = r#"{"frame_count":10,"frames":[{"first":"STRIKE","second":{"PINS":3}},{"first":{"PINS":1},"second":{"PINS":1}}]}"#;
+        let open_bonus = r#"{"frame_count":1,"frames":[{"first":{"PINS":7},"second":{"PINS":2},"bonus":{"PINS":7}}]}"#;
+        let overlap = r#"{"frame_count":10,"frames":[{"first":{"PINSET":[1,2,3,4,5,6,8]},"second":{"PINSET":[8,9]}}]}"#;
+
+        for (json, message) in [
+            (overflow, "frame 1, shot 2: 8 pins is more than were standing"),
+            (after_strike, "frame 1, shot 2: frame has too many shots"),
+            (open_bonus, "frame 1, shot 3: bonus shot wasn't earned"),
+            (overlap, "frame 1, shot 2: pin 8 was already knocked down")
+        ] {
+            assert_eq!(serde_json::from_str::<Game>(json).unwrap_err().to_string(), message);
+        }
+    }
+
+    #[test]
+    fn invalid_frame_counts() {
+        let none = r#"{"frame_count":0,"frames":[]}"#;
+        let huge = r#"{"frame_count":100000000000,"frames":[]}"#;
+
+        assert_eq!(serde_json::from_str::<Game>(none).unwrap_err().to_string(), "a game must have between 1 and 100 frames, not 0");
+        assert_eq!(serde_json::from_str::<Game>(huge).unwrap_err().to_string(), "a game must have between 1 and 100 frames, not 100000000000");
+    }
+}
\ No newline at end of file
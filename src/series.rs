@@ -0,0 +1,182 @@
+use std::{slice::Iter, vec::IntoIter};
+
+use crate::{Game, ScoreProvider, TenPinScoreProvider};
+
+/// Contains the [Game]s of a series, such as a three-game league night.
+#[derive(Clone, Debug, Default, Hash)]
+pub struct Series {
+    /// The handicap added to each [Game] of the [Series].
+    pub handicap: usize,
+    /// The games of the [Series].
+    games: Vec<Game>
+}
+
+impl Series {
+    /// Create a new [Series].
+    pub fn new(games: Vec<Game>) -> Self {
+        Self {
+            handicap: 0,
+            games
+        }
+    }
+
+    /// Create a new [Series] with a handicap for each [Game].
+    pub fn with_handicap(handicap: usize, games: Vec<Game>) -> Self {
+        Self {
+            handicap,
+            games
+        }
+    }
+
+    /// Returns an iterator for the [Game]s of the [Series].
+    pub fn iter(&self) -> Iter<'_, Game> {
+        self.games.iter()
+    }
+
+    /// Add a [Game] to the [Series].
+    pub fn add_game(&mut self, game: Game) {
+        self.games.push(game);
+    }
+
+    /// Get a [Game] from the [Series].
+    pub fn game(&self, index: usize) -> Option<&Game> {
+        self.games.get(index)
+    }
+
+    /// Count the [Game]s of the [Series].
+    pub fn len(&self) -> usize {
+        self.games.len()
+    }
+
+    /// Check if the [Series] has no [Game]s.
+    pub fn is_empty(&self) -> bool {
+        self.games.is_empty()
+    }
+
+    /// Calculate the score of each [Game] of the [Series] using a [ScoreProvider].
+    pub fn scores_with_provider(&self, provider: impl ScoreProvider) -> Vec<usize> {
+        self.games.iter().map(|game| game.score_with_provider(provider)).collect()
+    }
+
+    /// Calculate the score of each [Game] of the [Series] using the [TenPinScoreProvider].
+    pub fn scores(&self) -> Vec<usize> {
+        self.scores_with_provider(TenPinScoreProvider)
+    }
+
+    /// Calculate the scratch total of the [Series] using a [ScoreProvider].
+    pub fn total_with_provider(&self, provider: impl ScoreProvider) -> usize {
+        self.scores_with_provider(provider).iter().sum()
+    }
+
+    /// Calculate the scratch total of the [Series] using the [TenPinScoreProvider].
+    pub fn total(&self) -> usize {
+        self.total_with_provider(TenPinScoreProvider)
+    }
+
+    /// Calculate the total of the [Series] with handicap using a [ScoreProvider].
+    pub fn handicap_total_with_provider(&self, provider: impl ScoreProvider) -> usize {
+        self.total_with_provider(provider) + self.handicap * self.games.len()
+    }
+
+    /// Calculate the total of the [Series] with handicap using the [TenPinScoreProvider].
+    pub fn handicap_total(&self) -> usize {
+        self.handicap_total_with_provider(TenPinScoreProvider)
+    }
+
+    /// Calculate the scratch average of the [Series] using a [ScoreProvider], rounded down.
+    ///
+    /// Returns `None` if the [Series] has no [Game]s.
+    pub fn average_with_provider(&self, provider: impl ScoreProvider) -> Option<usize> {
+        (!self.games.is_empty()).then(|| self.total_with_provider(provider) / self.games.len())
+    }
+
+    /// Calculate the scratch average of the [Series] using the [TenPinScoreProvider], rounded down.
+    ///
+    /// Returns `None` if the [Series] has no [Game]s.
+    pub fn average(&self) -> Option<usize> {
+        self.average_with_provider(TenPinScoreProvider)
+    }
+
+    /// Find the highest scratch [Game] score of the [Series] using a [ScoreProvider].
+    pub fn high_game_with_provider(&self, provider: impl ScoreProvider) -> Option<usize> {
+        self.scores_with_provider(provider).into_iter().max()
+    }
+
+    /// Find the highest scratch [Game] score of the [Series] using the [TenPinScoreProvider].
+    pub fn high_game(&self) -> Option<usize> {
+        self.high_game_with_provider(TenPinScoreProvider)
+    }
+
+    /// Find the lowest scratch [Game] score of the [Series] using a [ScoreProvider].
+    pub fn low_game_with_provider(&self, provider: impl ScoreProvider) -> Option<usize> {
+        self.scores_with_provider(provider).into_iter().min()
+    }
+
+    /// Find the lowest scratch [Game] score of the [Series] using the [TenPinScoreProvider].
+    pub fn low_game(&self) -> Option<usize> {
+        self.low_game_with_provider(TenPinScoreProvider)
+    }
+}
+
+impl FromIterator<Game> for Series {
+    fn from_iter<I: IntoIterator<Item = Game>>(iter: I) -> Self {
+        Self::new(iter.into_iter().collect())
+    }
+}
+
+impl IntoIterator for Series {
+    type Item = Game;
+    type IntoIter = IntoIter<Game>;
+
+    /// Creates a consuming iterator for the [Game]s of the [Series].
+    fn into_iter(self) -> Self::IntoIter {
+        self.games.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Series {
+    type Item = &'a Game;
+    type IntoIter = Iter<'a, Game>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.games.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{FivePinScoreProvider, Series};
+    use crate::fixtures::{game, open_game, perfect_game};
+
+    fn series() -> Series {
+        Series::with_handicap(20, vec![perfect_game(), game(), open_game()])
+    }
+
+    #[test]
+    fn totals() {
+        let series = series();
+
+        assert_eq!(series.scores(), vec![300, 190, 90]);
+        assert_eq!(series.total(), 580);
+        assert_eq!(series.handicap_total(), 640);
+        assert_eq!(series.average(), Some(193));
+    }
+
+    #[test]
+    fn high_and_low() {
+        let series = series();
+
+        assert_eq!(series.high_game(), Some(300));
+        assert_eq!(series.low_game(), Some(90));
+        assert_eq!(Series::default().high_game(), None);
+        assert_eq!(Series::default().average(), None);
+    }
+
+    #[test]
+    fn provider() {
+        let series: Series = vec![perfect_game(), perfect_game()].into_iter().collect();
+
+        assert_eq!(series.total_with_provider(FivePinScoreProvider), 900);
+        assert_eq!(series.average_with_provider(FivePinScoreProvider), Some(450));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,164 @@
+use std::{fmt, ops::RangeInclusive};
+
+use crate::{Frame, PinSet, Score};
+
+/// Pairs of pins that touch in a ten-pin rack, including pins hidden directly behind another.
+const NEIGHBORS: [(usize, usize); 15] = [
+    (1, 2), (1, 3), (1, 5),
+    (2, 4), (2, 5), (2, 8),
+    (3, 5), (3, 6), (3, 9),
+    (4, 7), (4, 8),
+    (5, 8), (5, 9),
+    (6, 9), (6, 10)
+];
+
+/// The numbers of pins a first ball can knock down and still leave a split.
+pub(crate) const SPLIT_COUNTS: RangeInclusive<usize> = 2..=8;
+
+/// A well-known leave, named the way bowlers call it.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum NamedLeave {
+    /// The 7-10 split, also known as the "bedposts."
+    SevenTen,
+    /// The 4-6-7-10 split.
+    BigFour,
+    /// The 4-6-7-8-10 or 4-6-7-9-10 split.
+    GreekChurch,
+    /// The 2-7 or 3-10 split.
+    BabySplit,
+    /// The 1-2-10, 1-2-4-10, 1-3-7 or 1-3-6-7 leave.
+    Washout,
+    /// The 2-4-5-8 or 3-5-6-9 leave.
+    Bucket
+}
+
+impl NamedLeave {
+    /// Every [NamedLeave], with the pins of each leave it covers.
+    const LEAVES: [(NamedLeave, &'static [&'static [usize]]); 6] = [
+        (NamedLeave::SevenTen, &[&[7, 10]]),
+        (NamedLeave::BigFour, &[&[4, 6, 7, 10]]),
+        (NamedLeave::GreekChurch, &[&[4, 6, 7, 8, 10], &[4, 6, 7, 9, 10]]),
+        (NamedLeave::BabySplit, &[&[2, 7], &[3, 10]]),
+        (NamedLeave::Washout, &[&[1, 2, 10], &[1, 2, 4, 10], &[1, 3, 7], &[1, 3, 6, 7]]),
+        (NamedLeave::Bucket, &[&[2, 4, 5, 8], &[3, 5, 6, 9]])
+    ];
+
+    /// Find the [NamedLeave] for the pins left standing, if it has a name.
+    pub fn classify(leave: PinSet) -> Option<Self> {
+        Self::LEAVES.iter().find(|(_, leaves)| leaves.iter().any(|pins| PinSet::from_pins(pins) == leave)).map(|(name, _)| *name)
+    }
+}
+
+impl fmt::Display for NamedLeave {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            NamedLeave::SevenTen => "7-10 split",
+            NamedLeave::BigFour => "Big Four",
+            NamedLeave::GreekChurch => "Greek Church",
+            NamedLeave::BabySplit => "baby split",
+            NamedLeave::Washout => "washout",
+            NamedLeave::Bucket => "bucket"
+        };
+
+        write!(formatter, "{}", name)
+    }
+}
+
+impl PinSet {
+    /// Check if the pins left standing are a split.
+    ///
+    /// A split needs the headpin to be down and at least two pins standing,
+    /// with no chain of touching pins between them.
+    pub fn is_split(&self) -> bool {
+        if self.contains(1) || self.len() < 2 {
+            return false;
+        }
+
+        // Spread out from the first standing pin through the pins it touches
+        let mut reached = PinSet::from_pins(&[self.iter().next().unwrap()]);
+        let mut changed = true;
+
+        while changed {
+            changed = false;
+
+            for (a, b) in NEIGHBORS {
+                if self.contains(a) && self.contains(b) && reached.contains(a) != reached.contains(b) {
+                    reached.insert(a);
+                    reached.insert(b);
+                    changed = true;
+                }
+            }
+        }
+
+        reached != *self
+    }
+}
+
+impl Frame {
+    /// Check if the first shot of the [Frame] left a split.
+    ///
+    /// Only [Frame]s that recorded which pins the first shot knocked down, or marked it as a [Score]::SPLIT, can be splits.
+    pub fn is_split(&self) -> bool {
+        matches!(self.first, Score::SPLIT(_)) || self.leave().is_some_and(|leave| leave.is_split())
+    }
+
+    /// Find the [NamedLeave] left by the first shot of the [Frame], if it has a name.
+    ///
+    /// Returns `None` if the leave isn't known, such as after a [Score]::SPLIT.
+    pub fn named_leave(&self) -> Option<NamedLeave> {
+        self.leave().and_then(NamedLeave::classify)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{split::SPLIT_COUNTS, Frame, NamedLeave, PinSet, Score};
+
+    fn leave(pins: &[usize]) -> PinSet {
+        PinSet::from_pins(pins)
+    }
+
+    #[test]
+    fn splits() {
+        for pins in [&[7, 10][..], &[4, 6], &[5, 6], &[4, 5], &[2, 7], &[3, 10], &[4, 6, 7, 10], &[8, 10], &[4, 7, 10]] {
+            assert!(leave(pins).is_split(), "{:?} should be a split", pins);
+        }
+    }
+
+    #[test]
+    fn not_splits() {
+        for pins in [&[10][..], &[2, 4, 5, 8], &[2, 8], &[3, 9], &[6, 10], &[1, 2, 10], &[], &[1, 7]] {
+            assert!(!leave(pins).is_split(), "{:?} shouldn't be a split", pins);
+        }
+    }
+
+    #[test]
+    fn split_counts() {
+        for count in SPLIT_COUNTS {
+            let frame = Frame::new(Score::SPLIT(count), Score::PINS(10 - count));
+
+            assert!(frame.is_split());
+            assert_eq!(frame.leave(), None);
+            assert_eq!(frame.named_leave(), None);
+        }
+    }
+
+    #[test]
+    fn named_leaves() {
+        assert_eq!(NamedLeave::classify(leave(&[7, 10])), Some(NamedLeave::SevenTen));
+        assert_eq!(NamedLeave::classify(leave(&[4, 6, 7, 9, 10])), Some(NamedLeave::GreekChurch));
+        assert_eq!(NamedLeave::classify(leave(&[1, 3, 7])), Some(NamedLeave::Washout));
+        assert_eq!(NamedLeave::classify(leave(&[3, 5, 6, 9])), Some(NamedLeave::Bucket));
+        assert_eq!(NamedLeave::classify(leave(&[6, 10])), None);
+        assert_eq!(NamedLeave::BigFour.to_string(), "Big Four");
+    }
+
+    #[test]
+    fn frame_split() {
+        let frame = Frame::new(Score::PINSET(PinSet::all().difference(leave(&[3, 10]))), Score::PINS(1));
+
+        assert!(frame.is_split());
+        assert_eq!(frame.named_leave(), Some(NamedLeave::BabySplit));
+        assert!(!Frame::new(Score::PINS(8), Score::PINS(1)).is_split());
+    }
+}
\ No newline at end of file
@@ -0,0 +1,246 @@
+use crate::Game;
+
+/// Statistics for one or more classic 10-pin [Game]s.
+///
+/// Strikes, spares and first balls are counted for every rack, including the extra racks of the final frame.
+/// Open frames and clean games are counted by frame.
+/// Use [GameStats::merge] or [GameStats::from_iter] to combine the statistics of many [Game]s.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct GameStats {
+    /// The number of [Game]s.
+    pub games: usize,
+    /// The number of frames with at least one shot.
+    pub frames: usize,
+    /// The number of strikes.
+    pub strikes: usize,
+    /// The number of spares.
+    pub spares: usize,
+    /// The number of finished frames that are neither strikes nor spares.
+    pub opens: usize,
+    /// The number of pins knocked down.
+    pub pins: usize,
+    /// The number of first balls at a full rack.
+    pub first_balls: usize,
+    /// The number of pins knocked down by first balls.
+    pub first_ball_pins: usize,
+    /// The number of shots at a spare.
+    pub spare_chances: usize,
+    /// The number of shots at a spare with a single pin standing.
+    pub single_pin_chances: usize,
+    /// The number of spares made with a single pin standing.
+    pub single_pin_spares: usize,
+    /// The number of complete [Game]s with a strike or spare in every frame.
+    pub clean_games: usize,
+    /// The most strikes in a row within a single [Game].
+    pub longest_streak: usize
+}
+
+impl GameStats {
+    /// Create new [GameStats] for a [Game].
+    pub fn new(game: &Game) -> Self {
+        let mut stats = Self {
+            games: 1,
+            ..Self::default()
+        };
+        let mut streak = 0;
+
+        for frame in game.iter().take(game.frame_count) {
+            let shots = frame.shots();
+
+            if shots.is_empty() {
+                continue;
+            }
+
+            stats.frames += 1;
+
+            if shots.len() >= 2 && !frame.is_strike() && !frame.is_spare() {
+                stats.opens += 1;
+            }
+
+            // Pins knocked down by the first ball of the current rack, if it didn't strike
+            let mut first = None;
+
+            for pins in shots {
+                stats.record_ball(pins, first, &mut streak);
+                first = if first.is_none() && pins < 10 { Some(pins) } else { None };
+            }
+        }
+
+        stats.clean_games = Self::is_clean_game(game, true) as usize;
+
+        stats
+    }
+
+    /// Check if a [Game] has a strike or spare in every frame.
+    ///
+    /// If `final_frame` is set, the last frame is the final frame of a game and must have its bonus ball bowled.
+    pub(crate) fn is_clean_game(game: &Game, final_frame: bool) -> bool {
+        let marks = game.iter().take(game.frame_count).filter(|frame| frame.is_strike() || frame.is_spare()).count();
+        let complete = !final_frame || game.frame_count.checked_sub(1).and_then(|last| game.frame(last)).is_some_and(|frame| frame.shots().len() == 3);
+
+        marks == game.frame_count && complete
+    }
+
+    /// Count a single ball, given the pins knocked down by the first ball of the rack if this is a second ball.
+    ///
+    /// `streak` is the number of strikes in a row before this ball.
+    pub(crate) fn record_ball(&mut self, pins: usize, first: Option<usize>, streak: &mut usize) {
+        self.pins += pins;
+
+        match first {
+            None => {
+                self.first_balls += 1;
+                self.first_ball_pins += pins;
+
+                if pins >= 10 {
+                    self.strikes += 1;
+                    *streak += 1;
+                    self.longest_streak = self.longest_streak.max(*streak);
+                } else {
+                    *streak = 0;
+                }
+            },
+            Some(first_pins) => {
+                let single_pin = first_pins == 9;
+                let spare = first_pins + pins >= 10;
+
+                self.spare_chances += 1;
+                self.spares += spare as usize;
+                self.single_pin_chances += single_pin as usize;
+                self.single_pin_spares += (single_pin && spare) as usize;
+            }
+        }
+    }
+
+    /// Add the statistics of other [GameStats] to these [GameStats].
+    pub fn merge(&mut self, other: &GameStats) {
+        self.games += other.games;
+        self.frames += other.frames;
+        self.strikes += other.strikes;
+        self.spares += other.spares;
+        self.opens += other.opens;
+        self.pins += other.pins;
+        self.first_balls += other.first_balls;
+        self.first_ball_pins += other.first_ball_pins;
+        self.spare_chances += other.spare_chances;
+        self.single_pin_chances += other.single_pin_chances;
+        self.single_pin_spares += other.single_pin_spares;
+        self.clean_games += other.clean_games;
+        self.longest_streak = self.longest_streak.max(other.longest_streak);
+    }
+
+    /// Calculate the average number of pins knocked down by a first ball.
+    ///
+    /// Returns `None` if no first balls have been bowled.
+    pub fn first_ball_average(&self) -> Option<f64> {
+        ratio(self.first_ball_pins, self.first_balls)
+    }
+
+    /// Calculate the fraction of first balls that were strikes, from 0 to 1.
+    ///
+    /// Returns `None` if no first balls have been bowled.
+    pub fn strike_rate(&self) -> Option<f64> {
+        ratio(self.strikes, self.first_balls)
+    }
+
+    /// Calculate the fraction of spare chances that were made, from 0 to 1.
+    ///
+    /// Returns `None` if there were no spare chances.
+    pub fn spare_conversion(&self) -> Option<f64> {
+        ratio(self.spares, self.spare_chances)
+    }
+
+    /// Calculate the fraction of single-pin spare chances that were made, from 0 to 1.
+    ///
+    /// Returns `None` if there were no single-pin spare chances.
+    pub fn single_pin_conversion(&self) -> Option<f64> {
+        ratio(self.single_pin_spares, self.single_pin_chances)
+    }
+
+    /// Check if every [Game] was a clean game.
+    pub fn is_clean(&self) -> bool {
+        self.games > 0 && self.clean_games == self.games
+    }
+}
+
+impl<'a> FromIterator<&'a Game> for GameStats {
+    fn from_iter<I: IntoIterator<Item = &'a Game>>(iter: I) -> Self {
+        let mut stats = Self::default();
+
+        for game in iter {
+            stats.merge(&GameStats::new(game));
+        }
+
+        stats
+    }
+}
+
+impl Game {
+    /// Calculate the [GameStats] of the [Game].
+    pub fn stats(&self) -> GameStats {
+        GameStats::new(self)
+    }
+}
+
+/// Divide two counts, or return `None` if the denominator is 0.
+fn ratio(numerator: usize, denominator: usize) -> Option<f64> {
+    (denominator > 0).then(|| numerator as f64 / denominator as f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Frame, Game, GameStats};
+    use crate::fixtures::{clean_game, game};
+
+    #[test]
+    fn counts() {
+        let stats = game().stats();
+
+        assert_eq!(stats.frames, 10);
+        assert_eq!(stats.strikes, 6);
+        assert_eq!(stats.spares, 3);
+        assert_eq!(stats.opens, 2);
+        assert_eq!(stats.pins, 116);
+        assert_eq!(stats.longest_streak, 2);
+        assert!(!stats.is_clean());
+    }
+
+    #[test]
+    fn rates() {
+        let stats = game().stats();
+
+        assert_eq!(stats.first_ball_average(), Some(110.0 / 12.0));
+        assert_eq!(stats.strike_rate(), Some(0.5));
+        assert_eq!(stats.spare_conversion(), Some(0.6));
+        assert_eq!(stats.single_pin_chances, 3);
+        assert_eq!(stats.single_pin_spares, 2);
+        assert_eq!(GameStats::default().spare_conversion(), None);
+    }
+
+    #[test]
+    fn clean() {
+        assert!(clean_game().stats().is_clean());
+        assert_eq!(Game::new(vec![Frame::strike(); 9]).stats().clean_games, 0);
+
+        // The final frame's bonus ball hasn't been bowled yet
+        let mut unfinished = Game::new(vec![Frame::strike(); 9]);
+        unfinished.add_frame(Frame::strike());
+        assert_eq!(unfinished.stats().clean_games, 0);
+
+        let mut unfinished = Game::new(vec![Frame::spare(9); 9]);
+        unfinished.add_frame(Frame::spare(9));
+        assert_eq!(unfinished.stats().clean_games, 0);
+    }
+
+    #[test]
+    fn merge() {
+        let stats: GameStats = [game(), clean_game()].iter().collect();
+
+        assert_eq!(stats.games, 2);
+        assert_eq!(stats.clean_games, 1);
+        assert_eq!(stats.spares, 13);
+        assert_eq!(stats.single_pin_conversion(), Some(12.0 / 13.0));
+        assert_eq!(stats.longest_streak, 2);
+        assert!(!stats.is_clean());
+    }
+}
\ No newline at end of file
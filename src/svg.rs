@@ -0,0 +1,194 @@
+use std::fmt::Write;
+
+use crate::{Game, ScoreProvider, TenPinScoreProvider, scoresheet};
+
+/// Options for [Game::to_svg].
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct SvgOptions {
+    /// The name of the bowler, shown in a column before the first frame.
+    pub bowler: Option<String>,
+    /// The handicap of the bowler, shown in a column after the final frame with the handicap total.
+    pub handicap: Option<usize>,
+    /// The width of a frame with two boxes. Each box is a third of this width.
+    pub frame_width: usize
+}
+
+impl SvgOptions {
+    /// Create new [SvgOptions] for a bowler.
+    pub fn new(bowler: impl Into<String>) -> Self {
+        Self {
+            bowler: Some(bowler.into()),
+            ..Self::default()
+        }
+    }
+
+    /// Create new [SvgOptions] for a bowler with a handicap.
+    pub fn with_handicap(bowler: impl Into<String>, handicap: usize) -> Self {
+        Self {
+            handicap: Some(handicap),
+            ..Self::new(bowler)
+        }
+    }
+}
+
+impl Default for SvgOptions {
+    fn default() -> Self {
+        Self {
+            bowler: None,
+            handicap: None,
+            frame_width: 60
+        }
+    }
+}
+
+/// The height of the row of frame numbers.
+const HEADER_HEIGHT: usize = 20;
+
+/// Escape text for use in SVG or HTML markup.
+pub(crate) fn escape(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
+
+impl Game {
+    /// Draw the [Game] as a standalone SVG scoresheet, using a [ScoreProvider] for the totals.
+    ///
+    /// Each frame has its marks in small boxes along the top, with splits circled, and the running total beneath.
+    /// Totals are left blank until a frame is resolved.
+    pub fn to_svg_with_provider(&self, options: &SvgOptions, provider: impl ScoreProvider) -> String {
+        let cells = scoresheet::cells(self, provider);
+        let box_size = options.frame_width / 3;
+        let frame_height = box_size * 3;
+        let name_width = if options.bowler.is_some() { options.frame_width * 2 } else { 0 };
+        let extra_width = if options.handicap.is_some() { options.frame_width * 2 } else { 0 };
+        let frames_width: usize = cells.iter().map(|cell| options.frame_width + (cell.marks.len() - 2) * box_size).sum();
+        let width = name_width + frames_width + extra_width;
+        let height = HEADER_HEIGHT + frame_height;
+
+        // The baselines of the header, the marks and the totals
+        let header_y = HEADER_HEIGHT * 3 / 4;
+        let mark_y = HEADER_HEIGHT + box_size * 3 / 4;
+        let total_y = HEADER_HEIGHT + box_size * 2 + box_size / 3;
+
+        let mut lines = vec![];
+        let mut boxes = vec![];
+        let mut text = vec![];
+
+        if let Some(bowler) = &options.bowler {
+            boxes.push(format!(r#"<rect x="0" y="{}" width="{}" height="{}"/>"#, HEADER_HEIGHT, name_width, frame_height));
+            text.push(format!(r#"<text x="{}" y="{}" font-size="16">{}</text>"#, box_size / 2, HEADER_HEIGHT + frame_height / 2 + 6, escape(bowler)));
+        }
+
+        let mut x = name_width;
+
+        for (i, cell) in cells.iter().enumerate() {
+            let frame_width = options.frame_width + (cell.marks.len() - 2) * box_size;
+
+            text.push(format!(r#"<text x="{}" y="{}" text-anchor="middle" font-size="12">{}</text>"#, x + frame_width / 2, header_y, i + 1));
+            boxes.push(format!(r#"<rect x="{}" y="{}" width="{}" height="{}"/>"#, x, HEADER_HEIGHT, frame_width, frame_height));
+
+            for (j, mark) in cell.marks.iter().enumerate() {
+                let box_x = x + frame_width - (cell.marks.len() - j) * box_size;
+                let center = box_x + box_size / 2;
+                let mark = match cell.split {
+                    Some(pins) if j == 0 => pins.to_string(),
+                    _ => mark.clone()
+                };
+
+                boxes.push(format!(r#"<rect x="{}" y="{}" width="{}" height="{}"/>"#, box_x, HEADER_HEIGHT, box_size, box_size));
+
+                if j == 0 && cell.split.is_some() {
+                    lines.push(format!(r#"<circle cx="{}" cy="{}" r="{}"/>"#, center, HEADER_HEIGHT + box_size / 2, (box_size / 2).saturating_sub(2)));
+                }
+
+                if !mark.is_empty() {
+                    text.push(format!(r#"<text x="{}" y="{}" text-anchor="middle">{}</text>"#, center, mark_y, escape(&mark)));
+                }
+            }
+
+            if let Some(total) = cell.total {
+                text.push(format!(r#"<text x="{}" y="{}" text-anchor="middle" font-size="18">{}</text>"#, x + frame_width / 2, total_y, total));
+            }
+
+            x += frame_width;
+        }
+
+        if let Some(handicap) = options.handicap {
+            let total = provider.score(self) + handicap;
+
+            for (label, value) in [("HDCP", handicap), ("TOTAL", total)] {
+                text.push(format!(r#"<text x="{}" y="{}" text-anchor="middle" font-size="12">{}</text>"#, x + options.frame_width / 2, header_y, label));
+                boxes.push(format!(r#"<rect x="{}" y="{}" width="{}" height="{}"/>"#, x, HEADER_HEIGHT, options.frame_width, frame_height));
+                text.push(format!(r#"<text x="{}" y="{}" text-anchor="middle" font-size="18">{}</text>"#, x + options.frame_width / 2, HEADER_HEIGHT + frame_height / 2 + 6, value));
+                x += options.frame_width;
+            }
+        }
+
+        let mut svg = String::new();
+
+        let _ = writeln!(svg, r#"<svg xmlns="http://www.w3.org/2000/svg" width="{0}" height="{1}" viewBox="0 0 {0} {1}" font-family="sans-serif" font-size="14">"#, width, height);
+        let _ = writeln!(svg, r#"<rect width="{}" height="{}" fill="white"/>"#, width, height);
+        let _ = writeln!(svg, r#"<g fill="none" stroke="black">"#);
+
+        for element in boxes.iter().chain(&lines) {
+            let _ = writeln!(svg, "{}", element);
+        }
+
+        let _ = writeln!(svg, "</g>");
+
+        for element in &text {
+            let _ = writeln!(svg, "{}", element);
+        }
+
+        svg + "</svg>"
+    }
+
+    /// Draw the [Game] as a standalone SVG scoresheet, using the [TenPinScoreProvider] for the totals.
+    ///
+    /// Each frame has its marks in small boxes along the top, with splits circled, and the running total beneath.
+    /// Totals are left blank until a frame is resolved.
+    pub fn to_svg(&self, options: &SvgOptions) -> String {
+        self.to_svg_with_provider(options, TenPinScoreProvider)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Frame, Game, PinSet, Score, SvgOptions};
+
+    fn game() -> Game {
+        let split = Score::PINSET(PinSet::all().difference(PinSet::from_pins(&[7, 10])));
+        Game::with_frame_count(3, vec![Frame::strike(), Frame::new(split, Score::PINS(1)), Frame::with_bonus(Score::PINS(9), Score::SPARE, Score::STRIKE)])
+    }
+
+    #[test]
+    fn snapshot() {
+        let svg = game().to_svg(&SvgOptions::with_handicap("Homer & Marge", 25));
+        assert_eq!(svg, include_str!("snapshots/three_frames.svg"));
+    }
+
+    #[test]
+    fn plain() {
+        let svg = game().to_svg(&SvgOptions::default());
+
+        assert!(svg.starts_with(r#"<svg xmlns="http://www.w3.org/2000/svg" width="200" height="80""#));
+        assert!(!svg.contains("HDCP"));
+        assert!(svg.ends_with("</svg>"));
+    }
+
+    #[test]
+    fn unresolved_totals() {
+        let game = Game::new(vec![Frame::strike(), Frame::new(Score::PINS(7), Score::EMPTY)]);
+        let svg = game.to_svg(&SvgOptions::default());
+
+        assert!(!svg.contains(r#"font-size="18">"#));
+        assert!(svg.contains(r#"text-anchor="middle">7</text>"#));
+    }
+
+    #[test]
+    fn small_frames() {
+        for frame_width in [0, 3, 6, 11] {
+            let svg = game().to_svg(&SvgOptions { frame_width, ..SvgOptions::default() });
+            assert!(svg.contains("<circle"));
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,127 @@
+use crate::{Frame, FrameScore, Game, Score, ScoreProvider};
+
+/// Calculate the score for each [Frame] of a [Game] with three balls per frame.
+///
+/// Used by candlepin, duckpin and five-pin, which share the same scoring rules.
+/// `rack` is the value of knocking down every pin, and `shots` gets the value of each shot of a [Frame].
+pub(crate) fn computed(game: &Game, rack: usize, shots: impl Fn(&Frame) -> Vec<usize>) -> Vec<usize> {
+    let mut frames = vec![];
+    let mut score = 0;
+
+    for i in 0..game.frame_count {
+        if let Some(frame) = game.frame(i) {
+            let values = shots(frame);
+
+            // Add frame's score
+            score += values.iter().sum::<usize>();
+
+            // Add bonus points, except for the final frame, which bowls its own
+            if i < game.frame_count - 1 {
+                let bonus_shots = if values.first() == Some(&rack) {
+                    2
+                } else if values.len() >= 2 && values[0] + values[1] == rack {
+                    1
+                } else {
+                    0
+                };
+
+                score += game.iter().take(game.frame_count).skip(i + 1).flat_map(&shots).take(bonus_shots).sum::<usize>();
+            }
+
+            frames.push(score);
+        }
+    }
+
+    frames
+}
+
+/// Calculate the [FrameScore] for each [Frame] of a [Game] with three balls per frame.
+///
+/// Like [computed], `rack` is the value of knocking down every pin, and `shots` gets the value of each shot of a [Frame].
+pub(crate) fn frame_scores(game: &Game, rack: usize, shots: impl Fn(&Frame) -> Vec<usize>) -> Vec<FrameScore> {
+    let mut scores = vec![];
+    let mut running = Some(0);
+
+    for i in 0..game.frame_count {
+        let Some(frame) = game.frame(i) else {
+            break;
+        };
+
+        let values = shots(frame);
+        let base = values.iter().sum();
+
+        // The final frame always has three balls, and its bonus shots are part of the frame
+        let (complete, bonus_shots) = if i == game.frame_count - 1 {
+            (values.len() == 3, 0)
+        } else if values.first() == Some(&rack) {
+            (true, 2)
+        } else if values.len() >= 2 && values[0] + values[1] == rack {
+            (true, 1)
+        } else {
+            (values.len() == 3, 0)
+        };
+
+        let later: Vec<usize> = game.iter().take(game.frame_count).skip(i + 1).flat_map(&shots).take(bonus_shots).collect();
+        let bonus = later.iter().sum();
+        let resolved = complete && later.len() == bonus_shots;
+
+        running = running.filter(|_| resolved).map(|score| score + base + bonus);
+
+        scores.push(FrameScore {
+            running,
+            base,
+            bonus,
+            resolved
+        });
+    }
+
+    scores
+}
+
+/// Calculate the maximum score achievable for a [Game] with three balls per frame.
+pub(crate) fn max(game: &Game, provider: &impl ScoreProvider, rack: usize, shots: impl Fn(&Frame) -> Vec<usize>) -> usize {
+    let mut frames = vec![];
+
+    for i in 0..game.frame_count {
+        let frame = game.frame(i).copied().unwrap_or_default();
+        frames.push(best_frame(frame, i == game.frame_count - 1, rack, &shots));
+    }
+
+    Game::with_frame_count(game.frame_count, frames).score_with_provider(*provider)
+}
+
+/// Fill the missing balls of a three-ball [Frame] with the best possible shots.
+fn best_frame(frame: Frame, final_frame: bool, rack: usize, shots: impl Fn(&Frame) -> Vec<usize>) -> Frame {
+    let recorded = shots(&frame);
+    let mut scores = [frame.first, frame.second, frame.bonus.unwrap_or(Score::EMPTY)];
+    let mut standing = rack;
+    let mut fresh = true;
+
+    for (i, score) in scores.iter_mut().enumerate() {
+        let value = match recorded.get(i) {
+            Some(value) => *value,
+            None => {
+                *score = if fresh { Score::STRIKE } else { Score::SPARE };
+                standing
+            }
+        };
+
+        standing -= value.min(standing);
+        fresh = standing == 0;
+
+        if fresh {
+            // Only the final frame keeps going once the pins are cleared
+            if !final_frame {
+                break;
+            }
+
+            standing = rack;
+        }
+    }
+
+    Frame {
+        first: scores[0],
+        second: scores[1],
+        bonus: (!scores[2].is_empty()).then_some(scores[2])
+    }
+}
\ No newline at end of file
@@ -0,0 +1,429 @@
+use std::{cmp::Ordering, error::Error, fmt};
+
+use crate::{Bowler, Frame, Game, Series};
+
+/// A [Bowler] entered in a [Tournament], with their qualifying [Game]s.
+#[derive(Clone, Debug, Default, Hash)]
+pub struct Entrant {
+    /// The [Bowler].
+    pub bowler: Bowler,
+    /// The qualifying [Game]s of the [Bowler].
+    pub qualifying: Series
+}
+
+impl Entrant {
+    /// Create a new [Entrant].
+    pub fn new(bowler: Bowler, qualifying: Series) -> Self {
+        Self {
+            bowler,
+            qualifying
+        }
+    }
+}
+
+/// The format of the finals of a [Tournament].
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum FinalsFormat {
+    /// The lowest two seeds bowl first, and each winner bowls the next seed up, until the winner bowls the top seed.
+    #[default]
+    Stepladder,
+    /// A single-elimination bracket, where the top seeds get byes if the finalists don't fill the bracket.
+    Bracket
+}
+
+/// An error returned when a [Game] can't be recorded in a [Tournament].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum TournamentError {
+    /// Every [Match] of the [Tournament] has a winner.
+    Complete,
+    /// A roll-off [Game] didn't have exactly two frames.
+    InvalidRollOff {
+        /// The frame count of the [Game].
+        frame_count: usize
+    }
+}
+
+impl fmt::Display for TournamentError {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TournamentError::Complete => write!(formatter, "the tournament is already complete"),
+            TournamentError::InvalidRollOff { frame_count } => write!(formatter, "a roll-off has 2 frames, not {}", frame_count)
+        }
+    }
+}
+
+impl Error for TournamentError {}
+
+/// A match between two [Entrant]s of a [Tournament].
+///
+/// The first [Game]s recorded are the match [Game]s, and any after them are roll-offs to break a tie.
+#[derive(Clone, Debug, Default, Hash)]
+pub struct Match {
+    /// The indexes of the [Entrant]s, higher seed first.
+    pub entrants: [usize; 2],
+    /// The [Game]s bowled by each [Entrant], in order.
+    pub games: Vec<[Game; 2]>,
+    /// The bracket round of the [Match], starting from 0.
+    round: usize
+}
+
+impl Match {
+    /// Create a [Game] for a ninth-and-tenth-frame roll-off.
+    pub fn roll_off(ninth: Frame, tenth: Frame) -> Game {
+        Game::with_frame_count(2, vec![ninth, tenth])
+    }
+
+    /// Find the index of the [Entrant] who won the [Match], if it has been decided.
+    pub fn winner(&self) -> Option<usize> {
+        let [first, second] = self.games.last()?;
+
+        match first.score().cmp(&second.score()) {
+            Ordering::Greater => Some(self.entrants[0]),
+            Ordering::Less => Some(self.entrants[1]),
+            Ordering::Equal => None
+        }
+    }
+
+    /// Find the index of the [Entrant] who lost the [Match], if it has been decided.
+    pub fn loser(&self) -> Option<usize> {
+        self.winner().map(|winner| if winner == self.entrants[0] { self.entrants[1] } else { self.entrants[0] })
+    }
+
+    /// Check if the [Match] is tied and needs a roll-off.
+    pub fn needs_roll_off(&self) -> bool {
+        !self.games.is_empty() && self.winner().is_none()
+    }
+}
+
+/// The final place of an [Entrant] in a [Tournament].
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Placement {
+    /// The place, starting from 1. [Entrant]s knocked out in the same bracket round share a place.
+    pub place: usize,
+    /// The index of the [Entrant].
+    pub entrant: usize
+}
+
+/// A slot in the current round of a bracket.
+#[derive(Clone, Copy, Debug, Hash)]
+enum Slot {
+    /// An [Entrant] with a bye.
+    Bye(usize),
+    /// The index of a [Match].
+    Match(usize)
+}
+
+/// A tournament, with qualifying [Game]s and finals.
+///
+/// [Entrant]s are seeded by qualifying pinfall, with ties going to the higher qualifying game,
+/// and then to the earlier [Entrant].
+/// [Game]s are scored with the [TenPinScoreProvider](crate::TenPinScoreProvider).
+#[derive(Clone, Debug, Default, Hash)]
+pub struct Tournament {
+    /// The [Entrant]s of the [Tournament].
+    pub entrants: Vec<Entrant>,
+    /// The format of the finals.
+    pub format: FinalsFormat,
+    /// The indexes of the [Entrant]s, in seed order.
+    seeds: Vec<usize>,
+    /// The number of [Entrant]s in the finals.
+    finalists: usize,
+    /// The [Match]es of the finals, in the order they are bowled.
+    matches: Vec<Match>,
+    /// The current round of the bracket.
+    slots: Vec<Slot>,
+    /// The index of the [Entrant] who won the [Tournament].
+    champion: Option<usize>
+}
+
+impl Tournament {
+    /// Create a new [Tournament], with the top `finalists` seeds making the finals.
+    pub fn new(entrants: Vec<Entrant>, format: FinalsFormat, finalists: usize) -> Self {
+        let mut seeds: Vec<usize> = (0..entrants.len()).collect();
+
+        seeds.sort_by(|a, b| {
+            let (a, b) = (&entrants[*a].qualifying, &entrants[*b].qualifying);
+            b.total().cmp(&a.total()).then(b.high_game().cmp(&a.high_game()))
+        });
+
+        let mut tournament = Self {
+            finalists: finalists.min(entrants.len()),
+            entrants,
+            format,
+            seeds,
+            ..Self::default()
+        };
+
+        tournament.start();
+        tournament
+    }
+
+    /// Get the indexes of the [Entrant]s, in seed order.
+    pub fn seeds(&self) -> &[usize] {
+        &self.seeds
+    }
+
+    /// Get the [Match]es of the finals so far, in the order they are bowled.
+    pub fn matches(&self) -> &[Match] {
+        &self.matches
+    }
+
+    /// Get the next [Match] to be bowled, or `None` if the [Tournament] is complete.
+    pub fn current_match(&self) -> Option<&Match> {
+        self.matches.iter().find(|m| m.winner().is_none())
+    }
+
+    /// Record the [Game]s of the current [Match], returning the index of the winner if it was decided.
+    ///
+    /// After a tie, the next [Game]s must be roll-offs from [Match::roll_off].
+    pub fn record(&mut self, games: [Game; 2]) -> Result<Option<usize>, TournamentError> {
+        let current = self.matches.iter_mut().find(|m| m.winner().is_none()).ok_or(TournamentError::Complete)?;
+
+        if current.needs_roll_off() && let Some(game) = games.iter().find(|game| game.frame_count != 2) {
+            return Err(TournamentError::InvalidRollOff { frame_count: game.frame_count });
+        }
+
+        current.games.push(games);
+        let winner = current.winner();
+
+        if winner.is_some() {
+            self.advance();
+        }
+
+        Ok(winner)
+    }
+
+    /// Find the index of the [Entrant] who won the [Tournament], if it has been decided.
+    pub fn champion(&self) -> Option<usize> {
+        self.champion
+    }
+
+    /// Check if the [Tournament] is complete, with no [Match]es left to bowl.
+    ///
+    /// A [Tournament] with no finalists is complete as soon as it is created, and has no champion.
+    pub fn is_complete(&self) -> bool {
+        self.current_match().is_none()
+    }
+
+    /// Get the final [Placement] of every [Entrant], or `None` if the [Tournament] isn't complete.
+    ///
+    /// [Entrant]s who didn't make the finals are placed by seed.
+    pub fn placements(&self) -> Option<Vec<Placement>> {
+        if !self.is_complete() {
+            return None;
+        }
+
+        let mut placements = vec![];
+
+        if let Some(champion) = self.champion {
+            placements.push(Placement { place: 1, entrant: champion });
+        }
+
+        match self.format {
+            FinalsFormat::Stepladder => {
+                for (i, m) in self.matches.iter().rev().enumerate() {
+                    placements.extend(m.loser().map(|entrant| Placement { place: i + 2, entrant }));
+                }
+            },
+            FinalsFormat::Bracket => {
+                let size = self.finalists.next_power_of_two();
+
+                for m in &self.matches {
+                    placements.extend(m.loser().map(|entrant| Placement { place: (size >> (m.round + 1)) + 1, entrant }));
+                }
+            }
+        }
+
+        for (i, entrant) in self.seeds.iter().enumerate().skip(self.finalists) {
+            placements.push(Placement { place: i + 1, entrant: *entrant });
+        }
+
+        // Keep seed order within a shared place
+        placements.sort_by_key(|placement| (placement.place, self.seeds.iter().position(|seed| *seed == placement.entrant)));
+        Some(placements)
+    }
+
+    /// Create the first [Match]es of the finals.
+    fn start(&mut self) {
+        if self.finalists < 2 {
+            self.champion = self.seeds.first().copied().filter(|_| self.finalists == 1);
+            return;
+        }
+
+        match self.format {
+            FinalsFormat::Stepladder => {
+                let entrants = [self.seeds[self.finalists - 2], self.seeds[self.finalists - 1]];
+                self.push_match(entrants, 0);
+            },
+            FinalsFormat::Bracket => {
+                for pair in bracket_order(self.finalists.next_power_of_two()).chunks(2) {
+                    let slot = if pair[1] >= self.finalists {
+                        Slot::Bye(self.seeds[pair[0]])
+                    } else {
+                        Slot::Match(self.push_match([self.seeds[pair[0]], self.seeds[pair[1]]], 0))
+                    };
+
+                    self.slots.push(slot);
+                }
+            }
+        }
+    }
+
+    /// Create the next [Match]es of the finals once the last [Match] has a winner.
+    fn advance(&mut self) {
+        let Some(winner) = self.matches.last().and_then(Match::winner) else {
+            return;
+        };
+
+        match self.format {
+            FinalsFormat::Stepladder => {
+                // The lowest two seeds bowl the first match, so each match after adds one seed
+                match (self.finalists - 2).checked_sub(self.matches.len()) {
+                    Some(seed) => {
+                        self.push_match([self.seeds[seed], winner], 0);
+                    },
+                    None => self.champion = Some(winner)
+                }
+            },
+            FinalsFormat::Bracket => {
+                let winners: Option<Vec<usize>> = self.slots.iter().map(|slot| match slot {
+                    Slot::Bye(entrant) => Some(*entrant),
+                    Slot::Match(index) => self.matches[*index].winner()
+                }).collect();
+
+                let Some(winners) = winners else {
+                    return;
+                };
+
+                if winners.len() == 1 {
+                    self.champion = Some(winners[0]);
+                    return;
+                }
+
+                let round = self.matches.last().map_or(0, |m| m.round + 1);
+                self.slots = winners.chunks(2).map(|pair| Slot::Match(self.push_match([pair[0], pair[1]], round))).collect();
+            }
+        }
+    }
+
+    /// Add a [Match] to the finals, returning its index.
+    fn push_match(&mut self, entrants: [usize; 2], round: usize) -> usize {
+        self.matches.push(Match {
+            entrants,
+            games: vec![],
+            round
+        });
+
+        self.matches.len() - 1
+    }
+}
+
+/// Get the seed positions of a bracket in order, so that each pair of neighbors bowl each other.
+fn bracket_order(size: usize) -> Vec<usize> {
+    let mut order = vec![0];
+
+    while order.len() < size {
+        let count = order.len() * 2;
+        order = order.iter().flat_map(|seed| [*seed, count - 1 - seed]).collect();
+    }
+
+    order
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Bowler, Entrant, FinalsFormat, Frame, Game, Match, Placement, Score, Series, Tournament, TournamentError};
+
+    /// Create an open [Game] worth ten times its pins.
+    fn open(pins: usize) -> Game {
+        Game::new(vec![Frame::new(Score::PINS(pins), Score::PINS(0)); 10])
+    }
+
+    fn entrants() -> Vec<Entrant> {
+        [("Homer", [6, 6]), ("Marge", [9, 8]), ("Bart", [5, 5]), ("Lisa", [8, 8]), ("Maggie", [3, 9])].into_iter()
+            .map(|(name, games)| Entrant::new(Bowler::new(name, 150), games.into_iter().map(open).collect::<Series>()))
+            .collect()
+    }
+
+    #[test]
+    fn seeding() {
+        let tournament = Tournament::new(entrants(), FinalsFormat::Stepladder, 4);
+
+        // Homer and Maggie both have 120 pins, but Maggie has the higher game
+        assert_eq!(tournament.seeds(), &[1, 3, 4, 0, 2]);
+    }
+
+    #[test]
+    fn stepladder() {
+        let mut tournament = Tournament::new(entrants(), FinalsFormat::Stepladder, 4);
+
+        assert_eq!(tournament.current_match().unwrap().entrants, [4, 0]);
+        assert_eq!(tournament.record([open(7), open(6)]), Ok(Some(4)));
+        assert_eq!(tournament.current_match().unwrap().entrants, [3, 4]);
+        assert_eq!(tournament.record([open(5), open(6)]), Ok(Some(4)));
+        assert_eq!(tournament.record([open(8), open(7)]), Ok(Some(1)));
+        assert_eq!(tournament.record([open(8), open(7)]), Err(TournamentError::Complete));
+
+        assert_eq!(tournament.placements().unwrap(), vec![
+            Placement { place: 1, entrant: 1 },
+            Placement { place: 2, entrant: 4 },
+            Placement { place: 3, entrant: 3 },
+            Placement { place: 4, entrant: 0 },
+            Placement { place: 5, entrant: 2 }
+        ]);
+    }
+
+    #[test]
+    fn roll_off() {
+        let mut tournament = Tournament::new(entrants(), FinalsFormat::Stepladder, 2);
+
+        assert_eq!(tournament.record([open(7), open(7)]), Ok(None));
+        assert!(tournament.current_match().unwrap().needs_roll_off());
+        assert_eq!(tournament.record([open(7), open(6)]), Err(TournamentError::InvalidRollOff { frame_count: 10 }));
+
+        let strike = Match::roll_off(Frame::strike(), Frame::with_bonus(Score::PINS(9), Score::SPARE, Score::PINS(9)));
+        let open = Match::roll_off(Frame::new(Score::PINS(8), Score::PINS(1)), Frame::new(Score::PINS(7), Score::PINS(2)));
+
+        assert_eq!(strike.score(), 39);
+        assert_eq!(tournament.record([open, strike]), Ok(Some(3)));
+        assert_eq!(tournament.champion(), Some(3));
+    }
+
+    #[test]
+    fn bracket_with_bye() {
+        let mut tournament = Tournament::new(entrants(), FinalsFormat::Bracket, 3);
+
+        // The top seed gets a bye to the final
+        assert_eq!(tournament.current_match().unwrap().entrants, [3, 4]);
+        assert_eq!(tournament.record([open(5), open(6)]), Ok(Some(4)));
+        assert_eq!(tournament.current_match().unwrap().entrants, [1, 4]);
+        assert_eq!(tournament.record([open(5), open(6)]), Ok(Some(4)));
+
+        assert!(tournament.is_complete());
+        assert_eq!(tournament.placements().unwrap().iter().map(|placement| (placement.place, placement.entrant)).collect::<Vec<_>>(), vec![(1, 4), (2, 1), (3, 3), (4, 0), (5, 2)]);
+    }
+
+    #[test]
+    fn bracket_shared_places() {
+        let mut tournament = Tournament::new(entrants(), FinalsFormat::Bracket, 4);
+
+        assert_eq!(tournament.record([open(9), open(1)]), Ok(Some(1)));
+        assert_eq!(tournament.record([open(1), open(9)]), Ok(Some(4)));
+        assert_eq!(tournament.record([open(9), open(1)]), Ok(Some(1)));
+
+        let places: Vec<usize> = tournament.placements().unwrap().iter().map(|placement| placement.place).collect();
+        assert_eq!(places, vec![1, 2, 3, 3, 5]);
+    }
+
+    #[test]
+    fn no_finalists() {
+        for mut tournament in [Tournament::new(entrants(), FinalsFormat::Bracket, 0), Tournament::new(vec![], FinalsFormat::Stepladder, 4), Tournament::default()] {
+            assert!(tournament.is_complete());
+            assert_eq!(tournament.champion(), None);
+            assert_eq!(tournament.record([open(9), open(1)]), Err(TournamentError::Complete));
+        }
+
+        let places: Vec<usize> = Tournament::new(entrants(), FinalsFormat::Bracket, 0).placements().unwrap().iter().map(|placement| placement.place).collect();
+        assert_eq!(places, vec![1, 2, 3, 4, 5]);
+    }
+}
\ No newline at end of file